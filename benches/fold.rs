@@ -0,0 +1,24 @@
+//! Compares `fold_bytes` against `Iterator::fold` over the same bytes.
+
+use criterion::{Criterion, criterion_group, criterion_main};
+use praborrow_logistics::RawResource;
+use std::hint::black_box;
+
+fn bench_fold(c: &mut Criterion) {
+    let resource = RawResource::refine((0..64 * 1024).map(|i| i as u8).collect()).unwrap();
+    let mut group = c.benchmark_group("fold");
+    group.bench_function("iterator_fold", |b| {
+        b.iter(|| {
+            black_box(&resource)
+                .byte_iter()
+                .fold(0u32, |acc, x| acc.wrapping_add(u32::from(x)))
+        })
+    });
+    group.bench_function("fold_bytes", |b| {
+        b.iter(|| black_box(&resource).fold_bytes(0u32, |acc, x| acc.wrapping_add(u32::from(x))))
+    });
+    group.finish();
+}
+
+criterion_group!(benches, bench_fold);
+criterion_main!(benches);
@@ -0,0 +1,47 @@
+//! Compares `find_byte`/`find_slice` against naive scans.
+//!
+//! Run with and without `--features memchr` to compare the fallback and the
+//! accelerated paths.
+
+use criterion::{Criterion, criterion_group, criterion_main};
+use praborrow_logistics::RawResource;
+use std::hint::black_box;
+
+fn haystack() -> RawResource {
+    let mut data = vec![b'a'; 64 * 1024];
+    let len = data.len();
+    data[len - 4..].copy_from_slice(b"\r\n\r\n");
+    RawResource::refine(data).unwrap()
+}
+
+fn bench_find_byte(c: &mut Criterion) {
+    let resource = haystack();
+    let mut group = c.benchmark_group("find_byte");
+    group.bench_function("naive", |b| {
+        b.iter(|| black_box(&resource).iter().position(|&x| x == b'\r'))
+    });
+    group.bench_function("find_byte", |b| {
+        b.iter(|| black_box(&resource).find_byte(b'\r'))
+    });
+    group.finish();
+}
+
+fn bench_find_slice(c: &mut Criterion) {
+    let resource = haystack();
+    let mut group = c.benchmark_group("find_slice");
+    group.bench_function("naive", |b| {
+        b.iter(|| {
+            black_box(&resource)
+                .as_slice()
+                .windows(4)
+                .position(|w| w == b"\r\n\r\n")
+        })
+    });
+    group.bench_function("find_slice", |b| {
+        b.iter(|| black_box(&resource).find_slice(b"\r\n\r\n"))
+    });
+    group.finish();
+}
+
+criterion_group!(benches, bench_find_byte, bench_find_slice);
+criterion_main!(benches);
@@ -0,0 +1,148 @@
+//! Construction of resources with a guaranteed pointer alignment.
+
+use alloc::alloc::{Layout, alloc, dealloc, handle_alloc_error};
+use alloc::vec::Vec;
+use bytes::Bytes;
+
+use crate::{RawResource, RefinementError};
+
+/// A heap buffer allocated directly with an over-aligned `Layout`.
+///
+/// `Vec<u8>` always deallocates with alignment 1, so buffers from
+/// [`RawResource::refine_aligned`] are owned by this type instead and freed
+/// with the exact layout they were allocated with.
+struct AlignedBuf {
+    ptr: *mut u8,
+    len: usize,
+    layout: Layout,
+}
+
+// SAFETY: `AlignedBuf` uniquely owns its allocation and exposes it only
+// through `&self`, so it can be sent across threads like a `Box<[u8]>`.
+unsafe impl Send for AlignedBuf {}
+
+impl AlignedBuf {
+    fn copy_from(data: &[u8], align: usize) -> Result<Self, RefinementError> {
+        // Allocate at least one byte: zero-sized allocations are not allowed.
+        let layout = Layout::from_size_align(data.len().max(1), align)
+            .map_err(|_| RefinementError::CapacityOverflow)?;
+        // SAFETY: `layout` has a non-zero size.
+        let ptr = unsafe { alloc(layout) };
+        if ptr.is_null() {
+            handle_alloc_error(layout);
+        }
+        // SAFETY: `ptr` is valid for `data.len()` bytes and freshly allocated,
+        // so it cannot overlap `data`.
+        unsafe { core::ptr::copy_nonoverlapping(data.as_ptr(), ptr, data.len()) };
+        Ok(Self {
+            ptr,
+            len: data.len(),
+            layout,
+        })
+    }
+}
+
+impl AsRef<[u8]> for AlignedBuf {
+    fn as_ref(&self) -> &[u8] {
+        // SAFETY: the first `len` bytes were initialized by `copy_from`.
+        unsafe { core::slice::from_raw_parts(self.ptr, self.len) }
+    }
+}
+
+impl Drop for AlignedBuf {
+    fn drop(&mut self) {
+        // SAFETY: `ptr` was allocated with exactly `layout`.
+        unsafe { dealloc(self.ptr, self.layout) };
+    }
+}
+
+impl RawResource {
+    /// Refines `data` into a resource whose pointer is aligned to `align`.
+    ///
+    /// Zero-copy if the `Vec` allocation already satisfies `align`; otherwise
+    /// the bytes are copied into a buffer allocated with the requested
+    /// alignment, which is later freed with the matching layout. Writes via
+    /// [`into_mut`](Self::into_mut) copy into an ordinary buffer, so the
+    /// alignment only holds while the resource stays immutable.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`RefinementError::InvalidAlignment`] if `align` is not a power
+    /// of two, or [`RefinementError::CapacityOverflow`] if the aligned layout
+    /// is too large.
+    pub fn refine_aligned(data: Vec<u8>, align: usize) -> Result<Self, RefinementError> {
+        if !align.is_power_of_two() {
+            return Err(RefinementError::InvalidAlignment { align });
+        }
+        if data.as_ptr().addr().is_multiple_of(align) {
+            return Self::refine(data);
+        }
+        let buf = AlignedBuf::copy_from(&data, align)?;
+        Ok(Self::from_bytes(Bytes::from_owner(buf)))
+    }
+
+    /// Returns the numeric address of the data pointer, for debugging and
+    /// DMA descriptors.
+    #[inline]
+    pub fn ptr_addr(&self) -> usize {
+        self.as_ptr().addr()
+    }
+
+    /// Returns `true` if the data pointer is a multiple of `align`.
+    ///
+    /// Always `false` when `align` is not a power of two.
+    #[inline]
+    pub fn is_aligned_to(&self, align: usize) -> bool {
+        align.is_power_of_two() && self.ptr_addr().is_multiple_of(align)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_refine_aligned() {
+        for align in [1, 2, 16, 64, 4096] {
+            let data: Vec<u8> = (0..100).collect();
+            let resource = RawResource::refine_aligned(data, align).expect("valid alignment");
+            assert!(resource.is_aligned_to(align));
+            assert!(resource.iter().copied().eq(0..100));
+        }
+    }
+
+    #[test]
+    fn test_ptr_addr_matches_alignment() {
+        let resource = RawResource::refine_aligned(alloc::vec![0; 33], 16).expect("valid");
+        assert!(resource.is_aligned_to(16));
+        assert_eq!(resource.ptr_addr() % 16, 0);
+        assert_eq!(resource.ptr_addr(), resource.as_ptr() as usize);
+    }
+
+    #[test]
+    fn test_refine_aligned_empty_and_shared() {
+        let resource = RawResource::refine_aligned(Vec::new(), 64).expect("valid alignment");
+        assert!(resource.is_empty());
+        assert!(resource.is_aligned_to(64));
+
+        let resource = RawResource::refine_aligned(alloc::vec![1, 2, 3], 128).expect("valid");
+        let clone = resource.clone();
+        drop(resource);
+        assert_eq!(clone.as_slice(), &[1, 2, 3]);
+        assert!(clone.is_aligned_to(128));
+        let mut copy = clone.into_mut();
+        copy[0] = 9;
+        assert_eq!(copy.as_slice(), &[9, 2, 3]);
+    }
+
+    #[test]
+    fn test_refine_aligned_rejects_non_power_of_two() {
+        assert_eq!(
+            RawResource::refine_aligned(alloc::vec![1], 24),
+            Err(RefinementError::InvalidAlignment { align: 24 })
+        );
+        let resource = RawResource::from("x");
+        assert!(!resource.is_aligned_to(0));
+        assert!(!resource.is_aligned_to(3));
+    }
+}
@@ -0,0 +1,89 @@
+//! Resources paired with caller-defined metadata.
+
+use core::ops::Deref;
+
+use crate::RawResource;
+
+/// A resource travelling together with typed metadata, such as a timestamp,
+/// source address or frame type.
+///
+/// Derefs to the [`RawResource`], so byte-level methods work directly on the
+/// annotated value.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct ResourceAnnotated<T> {
+    resource: RawResource,
+    metadata: T,
+}
+
+impl<T> ResourceAnnotated<T> {
+    /// Pairs `resource` with `metadata`.
+    pub fn new(resource: RawResource, metadata: T) -> Self {
+        Self { resource, metadata }
+    }
+
+    /// Returns the resource.
+    #[inline]
+    pub fn resource(&self) -> &RawResource {
+        &self.resource
+    }
+
+    /// Returns the metadata.
+    #[inline]
+    pub fn metadata(&self) -> &T {
+        &self.metadata
+    }
+
+    /// Returns the metadata mutably.
+    #[inline]
+    pub fn metadata_mut(&mut self) -> &mut T {
+        &mut self.metadata
+    }
+
+    /// Separates the resource from its metadata.
+    pub fn split(self) -> (RawResource, T) {
+        (self.resource, self.metadata)
+    }
+}
+
+impl<T> Deref for ResourceAnnotated<T> {
+    type Target = RawResource;
+
+    #[inline]
+    fn deref(&self) -> &RawResource {
+        &self.resource
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Debug, PartialEq)]
+    struct Frame {
+        kind: u8,
+        timestamp: u64,
+    }
+
+    #[test]
+    fn test_annotated_access_and_split() {
+        let frame = Frame {
+            kind: 2,
+            timestamp: 1_700_000_000,
+        };
+        let mut annotated = ResourceAnnotated::new(RawResource::from(&[0xAA, 0xBB][..]), frame);
+        assert_eq!(annotated.len(), 2);
+        assert_eq!(annotated.resource().as_slice(), &[0xAA, 0xBB]);
+        assert_eq!(annotated.metadata().kind, 2);
+        annotated.metadata_mut().timestamp += 1;
+
+        let (resource, metadata) = annotated.split();
+        assert_eq!(resource.as_slice(), &[0xAA, 0xBB]);
+        assert_eq!(
+            metadata,
+            Frame {
+                kind: 2,
+                timestamp: 1_700_000_001
+            }
+        );
+    }
+}
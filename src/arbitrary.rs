@@ -0,0 +1,61 @@
+//! `arbitrary` support for fuzzing code that consumes [`RawResource`].
+//!
+//! A `cargo-fuzz` target can take resources directly as input:
+//!
+//! ```ignore
+//! #![no_main]
+//!
+//! use libfuzzer_sys::fuzz_target;
+//! use praborrow_logistics::{RawResource, ResourceCursor};
+//!
+//! fuzz_target!(|resource: RawResource| {
+//!     let mut cursor = ResourceCursor::new(&resource);
+//!     while cursor.read_u16_be().is_ok() {}
+//! });
+//! ```
+
+use arbitrary::{Arbitrary, Result, Unstructured};
+
+use crate::RawResource;
+
+/// Consumes a length-prefixed run of the fuzzer's input as the resource bytes.
+///
+/// Empty resources are valid and are generated too, since they are a useful
+/// edge case for consumers.
+impl<'a> Arbitrary<'a> for RawResource {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        let bytes: &[u8] = u.arbitrary()?;
+        Ok(Self::from(bytes))
+    }
+
+    fn arbitrary_take_rest(u: Unstructured<'a>) -> Result<Self> {
+        let bytes: &[u8] = Arbitrary::arbitrary_take_rest(u)?;
+        Ok(Self::from(bytes))
+    }
+
+    fn size_hint(depth: usize) -> (usize, Option<usize>) {
+        <&[u8] as Arbitrary>::size_hint(depth)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_take_rest_uses_all_input() {
+        let input = [1u8, 2, 3, 4, 5];
+        let resource =
+            RawResource::arbitrary_take_rest(Unstructured::new(&input)).expect("should succeed");
+        assert_eq!(resource.as_slice(), &input);
+    }
+
+    #[test]
+    fn test_arbitrary_is_prefix_of_input() {
+        let input: alloc::vec::Vec<u8> = (0..=255).collect();
+        let mut u = Unstructured::new(&input);
+        let resource = RawResource::arbitrary(&mut u).expect("should succeed");
+        assert!(resource.len() <= input.len());
+        assert!(input.starts_with(&resource));
+    }
+}
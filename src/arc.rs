@@ -0,0 +1,116 @@
+//! Atomically reference-counted shared ownership of a resource.
+
+use alloc::sync::Arc;
+use core::fmt;
+use core::ops::Deref;
+
+use crate::RawResource;
+
+/// A `RawResource` shared between owners through an `Arc`.
+///
+/// Cloning a `RawResource` already shares its bytes, but each clone is a
+/// separate value. `ArcResource` shares one `RawResource` value, so owners can
+/// check identity with [`ptr_eq`](Self::ptr_eq), observe the owner count, and
+/// recover exclusive ownership with [`try_unwrap`](Self::try_unwrap).
+///
+/// `ArcResource` is `Send + Sync` because `RawResource` is.
+#[derive(Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct ArcResource {
+    inner: Arc<RawResource>,
+}
+
+impl ArcResource {
+    /// Moves `resource` into a new shared allocation.
+    pub fn new(resource: RawResource) -> Self {
+        Self {
+            inner: Arc::new(resource),
+        }
+    }
+
+    /// Returns the inner resource if this is the only owner.
+    ///
+    /// # Errors
+    ///
+    /// Returns `self` unchanged if other owners still exist.
+    pub fn try_unwrap(self) -> Result<RawResource, ArcResource> {
+        Arc::try_unwrap(self.inner).map_err(|inner| Self { inner })
+    }
+
+    /// Returns the number of owners sharing this resource.
+    #[inline]
+    pub fn strong_count(this: &Self) -> usize {
+        Arc::strong_count(&this.inner)
+    }
+
+    /// Returns `true` if both handles share the same resource.
+    #[inline]
+    pub fn ptr_eq(this: &Self, other: &Self) -> bool {
+        Arc::ptr_eq(&this.inner, &other.inner)
+    }
+}
+
+impl Deref for ArcResource {
+    type Target = RawResource;
+
+    #[inline]
+    fn deref(&self) -> &RawResource {
+        &self.inner
+    }
+}
+
+impl AsRef<RawResource> for ArcResource {
+    #[inline]
+    fn as_ref(&self) -> &RawResource {
+        &self.inner
+    }
+}
+
+impl From<RawResource> for ArcResource {
+    fn from(resource: RawResource) -> Self {
+        Self::new(resource)
+    }
+}
+
+impl fmt::Debug for ArcResource {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ArcResource")
+            .field("strong_count", &Arc::strong_count(&self.inner))
+            .field("resource", &*self.inner)
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_arc_resource_is_send_sync() {
+        fn assert_send_sync<T: Send + Sync>() {}
+        assert_send_sync::<ArcResource>();
+        assert_send_sync::<RawResource>();
+    }
+
+    #[test]
+    fn test_clone_shares_and_try_unwrap() {
+        let shared = ArcResource::new(RawResource::from("frame"));
+        let other = shared.clone();
+        assert!(ArcResource::ptr_eq(&shared, &other));
+        assert_eq!(ArcResource::strong_count(&shared), 2);
+        assert_eq!(other.as_slice(), b"frame");
+
+        let shared = shared.try_unwrap().expect_err("still shared");
+        drop(other);
+        let resource = shared.try_unwrap().expect("sole owner");
+        assert_eq!(resource.as_slice(), b"frame");
+    }
+
+    #[test]
+    fn test_debug() {
+        let shared = ArcResource::new(RawResource::from(&[0xAB][..]));
+        assert_eq!(
+            alloc::format!("{shared:?}"),
+            "ArcResource { strong_count: 1, resource: RawResource { len: 1, data: \"ab\" } }"
+        );
+    }
+}
@@ -0,0 +1,95 @@
+//! Base64 encoding and decoding via the `base64` crate.
+
+use alloc::string::String;
+
+use ::base64::engine::DecodePaddingMode;
+use ::base64::engine::general_purpose::{GeneralPurpose, GeneralPurposeConfig};
+use ::base64::{DecodeError, Engine, alphabet};
+
+use crate::RawResource;
+
+/// Encodes with padding and decodes with or without it.
+const CONFIG: GeneralPurposeConfig =
+    GeneralPurposeConfig::new().with_decode_padding_mode(DecodePaddingMode::Indifferent);
+const STANDARD: GeneralPurpose = GeneralPurpose::new(&alphabet::STANDARD, CONFIG);
+const URL_SAFE: GeneralPurpose = GeneralPurpose::new(&alphabet::URL_SAFE, CONFIG);
+
+/// The base64 alphabet to encode or decode with (RFC 4648).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum Base64Variant {
+    /// The standard alphabet using `+` and `/`, as in PEM and MIME.
+    #[default]
+    Standard,
+    /// The URL- and filename-safe alphabet using `-` and `_`, as in JWTs.
+    UrlSafe,
+}
+
+impl Base64Variant {
+    fn engine(self) -> &'static GeneralPurpose {
+        match self {
+            Self::Standard => &STANDARD,
+            Self::UrlSafe => &URL_SAFE,
+        }
+    }
+}
+
+/// Error returned when decoding a base64 string.
+#[derive(thiserror::Error, Debug, Clone, PartialEq, Eq)]
+pub enum Base64Error {
+    /// The input contains a byte outside the selected alphabet.
+    #[error("invalid base64 byte {byte:#04x} at index {index}")]
+    InvalidByte {
+        /// Offset of the byte in the input.
+        index: usize,
+        /// The offending byte.
+        byte: u8,
+    },
+    /// The input has a number of symbols that cannot encode whole bytes.
+    #[error("invalid base64 length {len}")]
+    InvalidLength {
+        /// Number of symbols, excluding padding.
+        len: usize,
+    },
+    /// The final symbol has non-zero trailing bits.
+    #[error("invalid last base64 symbol {byte:#04x} at index {index}")]
+    InvalidLastSymbol {
+        /// Offset of the symbol in the input.
+        index: usize,
+        /// The offending symbol.
+        byte: u8,
+    },
+    /// Padding is present where it is not allowed.
+    #[error("invalid base64 padding")]
+    InvalidPadding,
+}
+
+impl From<DecodeError> for Base64Error {
+    fn from(err: DecodeError) -> Self {
+        match err {
+            DecodeError::InvalidByte(index, byte) => Self::InvalidByte { index, byte },
+            DecodeError::InvalidLength(len) => Self::InvalidLength { len },
+            DecodeError::InvalidLastSymbol(index, byte) => Self::InvalidLastSymbol { index, byte },
+            DecodeError::InvalidPadding => Self::InvalidPadding,
+        }
+    }
+}
+
+impl RawResource {
+    /// Decodes a base64 string in the given alphabet into a new resource.
+    ///
+    /// Trailing `=` padding is optional, so unpadded JWT segments decode as
+    /// well as padded PEM bodies.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`Base64Error`] if `s` is not valid base64 in `variant`.
+    pub fn refine_from_base64(s: &str, variant: Base64Variant) -> Result<Self, Base64Error> {
+        let data = variant.engine().decode(s)?;
+        Ok(Self::refine(data).expect("refine is infallible"))
+    }
+
+    /// Encodes the bytes as padded base64 in the given alphabet.
+    pub fn as_base64(&self, variant: Base64Variant) -> String {
+        variant.engine().encode(self.as_slice())
+    }
+}
@@ -0,0 +1,87 @@
+//! Bit-level access for protocol flag fields.
+
+use crate::{RawResource, RefinementError};
+
+impl RawResource {
+    /// Returns bit `bit_index`, counting from the most significant bit of
+    /// the first byte (network bit order), or `None` if out of range.
+    #[inline]
+    pub fn bit_at(&self, bit_index: usize) -> Option<bool> {
+        let byte = self.get(bit_index / 8)?;
+        Some(byte & (0x80 >> (bit_index % 8)) != 0)
+    }
+
+    /// Sets bit `bit_index`, in the same order as [`bit_at`](Self::bit_at),
+    /// to `val`.
+    ///
+    /// Copies the bytes first if the buffer is shared.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`RefinementError::OutOfBounds`] if `bit_index >= len() * 8`;
+    /// the reported length is in bits.
+    pub fn set_bit(&mut self, bit_index: usize, val: bool) -> Result<(), RefinementError> {
+        let byte_index = bit_index / 8;
+        if byte_index >= self.len() {
+            return Err(RefinementError::OutOfBounds {
+                index: bit_index,
+                len: self.len().saturating_mul(8),
+            });
+        }
+        let mask = 0x80 >> (bit_index % 8);
+        self.modify(|buf| {
+            if val {
+                buf[byte_index] |= mask;
+            } else {
+                buf[byte_index] &= !mask;
+            }
+        });
+        Ok(())
+    }
+
+    /// Returns the number of set bits, saturating at `u32::MAX`.
+    pub fn count_ones(&self) -> u32 {
+        let ones: usize = self.iter().map(|byte| byte.count_ones() as usize).sum();
+        u32::try_from(ones).unwrap_or(u32::MAX)
+    }
+
+    /// Returns the number of clear bits, saturating at `u32::MAX`.
+    pub fn count_zeros(&self) -> u32 {
+        let zeros: usize = self.iter().map(|byte| byte.count_zeros() as usize).sum();
+        u32::try_from(zeros).unwrap_or(u32::MAX)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_bit_at_order() {
+        let flags = RawResource::from(&[0b1000_0001, 0b0100_0000][..]);
+        assert_eq!(flags.bit_at(0), Some(true));
+        assert_eq!(flags.bit_at(1), Some(false));
+        assert_eq!(flags.bit_at(7), Some(true));
+        assert_eq!(flags.bit_at(9), Some(true));
+        assert_eq!(flags.bit_at(16), None);
+        assert_eq!(flags.count_ones(), 3);
+        assert_eq!(flags.count_zeros(), 13);
+    }
+
+    #[test]
+    fn test_set_bit() {
+        let mut flags = RawResource::from(&[0u8; 2][..]);
+        let original = flags.clone();
+        flags.set_bit(0, true).unwrap();
+        flags.set_bit(15, true).unwrap();
+        assert_eq!(flags.as_slice(), &[0x80, 0x01]);
+        flags.set_bit(0, false).unwrap();
+        assert_eq!(flags.as_slice(), &[0x00, 0x01]);
+        assert_eq!(original.as_slice(), &[0, 0]);
+
+        assert_eq!(
+            flags.set_bit(16, true),
+            Err(RefinementError::OutOfBounds { index: 16, len: 16 })
+        );
+    }
+}
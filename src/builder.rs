@@ -0,0 +1,169 @@
+//! Incremental construction of resources.
+
+use alloc::vec::Vec;
+
+use crate::{RawResource, RefinementError};
+
+/// Accumulates bytes in a growable buffer before refining them.
+///
+/// ```
+/// use praborrow_logistics::ResourceBuilder;
+///
+/// let mut builder = ResourceBuilder::new();
+/// builder.push_byte(0x01);
+/// builder.push_u32_be(0xDEAD_BEEF);
+/// builder.push_slice(b"ok");
+/// let resource = builder.build().unwrap();
+/// assert_eq!(resource.as_slice(), &[0x01, 0xDE, 0xAD, 0xBE, 0xEF, b'o', b'k']);
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct ResourceBuilder {
+    buf: Vec<u8>,
+}
+
+impl ResourceBuilder {
+    /// Creates an empty builder.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Creates an empty builder with room for `capacity` bytes.
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self {
+            buf: Vec::with_capacity(capacity),
+        }
+    }
+
+    /// Returns the number of bytes accumulated so far.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.buf.len()
+    }
+
+    /// Returns `true` if no bytes have been pushed.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.buf.is_empty()
+    }
+
+    /// Appends a single byte.
+    pub fn push_byte(&mut self, b: u8) {
+        self.buf.push(b);
+    }
+
+    /// Appends a slice of bytes.
+    pub fn push_slice(&mut self, s: &[u8]) {
+        self.buf.extend_from_slice(s);
+    }
+
+    /// Appends a `u16` in big-endian byte order.
+    pub fn push_u16_be(&mut self, v: u16) {
+        self.push_slice(&v.to_be_bytes());
+    }
+
+    /// Appends a `u32` in big-endian byte order.
+    pub fn push_u32_be(&mut self, v: u32) {
+        self.push_slice(&v.to_be_bytes());
+    }
+
+    /// Appends a `u64` in big-endian byte order.
+    pub fn push_u64_be(&mut self, v: u64) {
+        self.push_slice(&v.to_be_bytes());
+    }
+
+    /// Appends a `u16` in little-endian byte order.
+    pub fn push_u16_le(&mut self, v: u16) {
+        self.push_slice(&v.to_le_bytes());
+    }
+
+    /// Appends a `u32` in little-endian byte order.
+    pub fn push_u32_le(&mut self, v: u32) {
+        self.push_slice(&v.to_le_bytes());
+    }
+
+    /// Appends a `u64` in little-endian byte order.
+    pub fn push_u64_le(&mut self, v: u64) {
+        self.push_slice(&v.to_le_bytes());
+    }
+
+    /// Appends `v` as an unsigned LEB128 varint, as used by Protocol Buffers.
+    pub fn write_varint_leb128(&mut self, mut v: u64) {
+        while v >= 0x80 {
+            self.push_byte((v as u8) | 0x80);
+            v >>= 7;
+        }
+        self.push_byte(v as u8);
+    }
+
+    /// Appends `v` as a zigzag-encoded LEB128 varint (Protocol Buffers
+    /// `sint64`), so small negative numbers stay short.
+    pub fn write_varint_zigzag(&mut self, v: i64) {
+        self.write_varint_leb128(((v << 1) ^ (v >> 63)) as u64);
+    }
+
+    /// Finalizes the accumulated bytes into a resource without copying.
+    ///
+    /// # Errors
+    ///
+    /// Propagates any error from [`RawResource::refine`].
+    pub fn build(self) -> Result<RawResource, RefinementError> {
+        RawResource::refine(self.buf)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_builder_encodes_big_endian() {
+        let mut builder = ResourceBuilder::with_capacity(14);
+        builder.push_u16_be(0x0102);
+        builder.push_u32_be(0x0304_0506);
+        builder.push_u64_be(0x0708_090A_0B0C_0D0E);
+        assert_eq!(builder.len(), 14);
+        let resource = builder.build().expect("should succeed");
+        assert!(resource.iter().copied().eq(1..=14));
+    }
+
+    #[test]
+    fn test_empty_builder() {
+        let builder = ResourceBuilder::new();
+        assert!(builder.is_empty());
+        assert!(builder.build().expect("should succeed").is_empty());
+    }
+
+    #[test]
+    fn test_builder_writes_protobuf_varints() {
+        let mut builder = ResourceBuilder::new();
+        builder.write_varint_leb128(1);
+        builder.write_varint_leb128(150);
+        builder.write_varint_leb128(300);
+        assert_eq!(
+            builder.build().expect("should succeed").as_slice(),
+            &[0x01, 0x96, 0x01, 0xAC, 0x02]
+        );
+
+        let mut builder = ResourceBuilder::new();
+        builder.write_varint_leb128(u64::MAX);
+        assert_eq!(
+            builder.build().expect("should succeed").as_slice(),
+            &[0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0x01]
+        );
+
+        for (v, expected) in [
+            (0, 0),
+            (-1, 1),
+            (1, 2),
+            (-2, 3),
+            (2_147_483_647, 4_294_967_294),
+            (-2_147_483_648, 4_294_967_295),
+        ] {
+            let mut zigzag = ResourceBuilder::new();
+            zigzag.write_varint_zigzag(v);
+            let mut plain = ResourceBuilder::new();
+            plain.write_varint_leb128(expected);
+            assert_eq!(zigzag.build(), plain.build());
+        }
+    }
+}
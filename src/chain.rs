@@ -0,0 +1,176 @@
+//! Logical concatenation of resources without copying.
+
+use alloc::vec::Vec;
+
+use crate::merge::merge_all;
+use crate::{RawResource, RefinementError};
+
+/// An ordered sequence of resources that upper layers treat as contiguous.
+///
+/// Segments are stored as-is, so building a chain never copies payload bytes.
+/// Dropping the chain drops every held segment.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct ResourceChain {
+    segments: Vec<RawResource>,
+}
+
+impl ResourceChain {
+    /// Creates an empty chain.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Appends a segment to the end of the chain.
+    pub fn push(&mut self, resource: RawResource) {
+        self.segments.push(resource);
+    }
+
+    /// Returns the number of segments in the chain.
+    #[inline]
+    pub fn segment_count(&self) -> usize {
+        self.segments.len()
+    }
+
+    /// Returns the held segments in order.
+    #[inline]
+    pub fn segments(&self) -> &[RawResource] {
+        &self.segments
+    }
+
+    /// Returns the combined length of all segments in bytes.
+    pub fn total_len(&self) -> usize {
+        self.segments.iter().map(RawResource::len).sum()
+    }
+
+    /// Iterates over every byte of every segment, in order.
+    pub fn iter_bytes(&self) -> impl Iterator<Item = u8> + '_ {
+        self.segments
+            .iter()
+            .flat_map(|segment| segment.as_slice().iter().copied())
+    }
+
+    /// Copies all segments into a single, newly allocated resource.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`RefinementError::CapacityOverflow`] if the combined length
+    /// does not fit in `usize`.
+    pub fn into_merged(self) -> Result<RawResource, RefinementError> {
+        merge_all(&self.segments)
+    }
+}
+
+/// A group of resources that are owned, and freed, together.
+///
+/// Intended for scatter-gather IO or multi-part payloads where the number of
+/// parts varies. Dropping or [`clear`](Self::clear)ing the set drops every
+/// held resource.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct ResourceSet(Vec<RawResource>);
+
+impl ResourceSet {
+    /// Creates an empty set.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds a resource to the set.
+    pub fn push(&mut self, resource: RawResource) {
+        self.0.push(resource);
+    }
+
+    /// Iterates over the held resources in insertion order.
+    pub fn iter(&self) -> impl ExactSizeIterator<Item = &RawResource> {
+        self.0.iter()
+    }
+
+    /// Returns the number of resources in the set.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    /// Returns `true` if the set holds no resources.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// Drops every held resource.
+    pub fn clear(&mut self) {
+        self.0.clear();
+    }
+
+    /// Concatenates all resources, in insertion order, into a new resource.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`RefinementError::CapacityOverflow`] if the combined length
+    /// does not fit in `usize`.
+    pub fn into_merged(self) -> Result<RawResource, RefinementError> {
+        merge_all(&self.0)
+    }
+}
+
+impl FromIterator<RawResource> for ResourceSet {
+    fn from_iter<I: IntoIterator<Item = RawResource>>(iter: I) -> Self {
+        Self(iter.into_iter().collect())
+    }
+}
+
+impl FromIterator<RawResource> for ResourceChain {
+    fn from_iter<I: IntoIterator<Item = RawResource>>(iter: I) -> Self {
+        Self {
+            segments: iter.into_iter().collect(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_chain_len_and_bytes() {
+        let mut chain = ResourceChain::new();
+        chain.push(RawResource::from(&[0xAA, 0xBB][..]));
+        chain.push(RawResource::from(&[][..]));
+        chain.push(RawResource::from(&[1, 2, 3][..]));
+
+        assert_eq!(chain.segment_count(), 3);
+        assert_eq!(chain.total_len(), 5);
+        assert!(chain.iter_bytes().eq([0xAA, 0xBB, 1, 2, 3]));
+    }
+
+    #[test]
+    fn test_chain_into_merged() {
+        let chain: ResourceChain = [RawResource::from("head:"), RawResource::from("body")]
+            .into_iter()
+            .collect();
+        let merged = chain.into_merged().expect("should succeed");
+        assert_eq!(merged.as_slice(), b"head:body");
+    }
+
+    #[test]
+    fn test_resource_set() {
+        let mut set = ResourceSet::new();
+        assert!(set.is_empty());
+        set.push(RawResource::from("part1;"));
+        set.push(RawResource::from("part2"));
+        assert_eq!(set.len(), 2);
+        assert_eq!(set.iter().map(RawResource::len).sum::<usize>(), 11);
+
+        let merged = set.clone().into_merged().expect("should succeed");
+        assert_eq!(merged.as_slice(), b"part1;part2");
+
+        set.clear();
+        assert!(set.is_empty());
+        assert!(set.into_merged().expect("should succeed").is_empty());
+    }
+
+    #[test]
+    fn test_empty_chain_merges_to_empty() {
+        let merged = ResourceChain::new().into_merged().expect("should succeed");
+        assert!(merged.is_empty());
+    }
+}
@@ -0,0 +1,44 @@
+//! Integrity checksums over resource contents.
+
+use crate::RawResource;
+
+#[cfg(feature = "crc")]
+const CRC32: crc::Crc<u32> = crc::Crc::<u32>::new(&crc::CRC_32_ISO_HDLC);
+
+impl RawResource {
+    /// Computes the Fletcher-16 checksum of the resource.
+    pub fn checksum_fletcher16(&self) -> u16 {
+        let (mut sum1, mut sum2) = (0u16, 0u16);
+        for &byte in self.as_slice() {
+            sum1 = (sum1 + u16::from(byte)) % 255;
+            sum2 = (sum2 + sum1) % 255;
+        }
+        (sum2 << 8) | sum1
+    }
+
+    /// Computes the CRC-32 (ISO-HDLC, as used by zlib and Ethernet) checksum.
+    #[cfg(feature = "crc")]
+    pub fn checksum_crc32(&self) -> u32 {
+        CRC32.checksum(self.as_slice())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_fletcher16_vectors() {
+        assert_eq!(RawResource::from("abcde").checksum_fletcher16(), 0xC8F0);
+        assert_eq!(RawResource::from("abcdef").checksum_fletcher16(), 0x2057);
+        assert_eq!(RawResource::from("abcdefgh").checksum_fletcher16(), 0x0627);
+        assert_eq!(RawResource::from("").checksum_fletcher16(), 0);
+    }
+
+    #[cfg(feature = "crc")]
+    #[test]
+    fn test_crc32_check_value() {
+        assert_eq!(RawResource::from("123456789").checksum_crc32(), 0xCBF4_3926);
+        assert_eq!(RawResource::from("").checksum_crc32(), 0);
+    }
+}
@@ -0,0 +1,129 @@
+//! Encoder and decoder traits for compression, encryption and framing.
+
+use crate::{RawResource, RefinementError};
+
+/// Turns a resource into its encoded form.
+///
+/// Object-safe, so heterogeneous pipelines can hold
+/// `Box<dyn ResourceEncoder>` stages.
+///
+/// # Example
+///
+/// A minimal run-length encoder emitting `(count, value)` pairs:
+///
+/// ```
+/// use praborrow_logistics::{RawResource, RefinementError, ResourceEncoder};
+///
+/// struct PairRle;
+///
+/// impl ResourceEncoder for PairRle {
+///     fn encode(&self, input: &RawResource) -> Result<RawResource, RefinementError> {
+///         let mut out = Vec::new();
+///         for run in input.as_slice().chunk_by(|a, b| a == b) {
+///             for part in run.chunks(255) {
+///                 out.extend([part.len() as u8, part[0]]);
+///             }
+///         }
+///         RawResource::refine(out)
+///     }
+/// }
+///
+/// let stages: Vec<Box<dyn ResourceEncoder>> = vec![Box::new(PairRle)];
+/// let encoded = stages[0].encode(&RawResource::from("aaab")).unwrap();
+/// assert_eq!(encoded.as_slice(), &[3, b'a', 1, b'b']);
+/// ```
+pub trait ResourceEncoder {
+    /// Encodes `input` into a new resource.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`RefinementError`] if `input` cannot be encoded.
+    fn encode(&self, input: &RawResource) -> Result<RawResource, RefinementError>;
+}
+
+/// Recovers a resource from its encoded form.
+///
+/// Object-safe, so heterogeneous pipelines can hold
+/// `Box<dyn ResourceDecoder>` stages.
+///
+/// # Example
+///
+/// The decoder matching the `(count, value)` encoder on [`ResourceEncoder`]:
+///
+/// ```
+/// use praborrow_logistics::{RawResource, RefinementError, ResourceDecoder};
+///
+/// struct PairRle;
+///
+/// impl ResourceDecoder for PairRle {
+///     fn decode(&self, input: &RawResource) -> Result<RawResource, RefinementError> {
+///         let pairs = input.as_slice().chunks_exact(2);
+///         if !pairs.remainder().is_empty() {
+///             return Err(RefinementError::Exhausted { needed: 1, remaining: 0 });
+///         }
+///         let out = pairs.flat_map(|pair| std::iter::repeat_n(pair[1], pair[0].into()));
+///         RawResource::refine(out.collect())
+///     }
+/// }
+///
+/// let decoded = PairRle.decode(&RawResource::from(&[3, b'a', 1, b'b'][..])).unwrap();
+/// assert_eq!(decoded.as_slice(), b"aaab");
+/// ```
+pub trait ResourceDecoder {
+    /// Decodes `input` into a new resource.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`RefinementError`] if `input` is not validly encoded.
+    fn decode(&self, input: &RawResource) -> Result<RawResource, RefinementError>;
+}
+
+/// A passthrough codec that returns its input unchanged.
+///
+/// Encoding and decoding are zero-copy: the output shares the input's
+/// buffer. Useful as a default pipeline stage or a test double.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct IdentityCodec;
+
+impl ResourceEncoder for IdentityCodec {
+    #[inline]
+    fn encode(&self, input: &RawResource) -> Result<RawResource, RefinementError> {
+        Ok(input.clone())
+    }
+}
+
+impl ResourceDecoder for IdentityCodec {
+    #[inline]
+    fn decode(&self, input: &RawResource) -> Result<RawResource, RefinementError> {
+        Ok(input.clone())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::boxed::Box;
+
+    #[test]
+    fn test_identity_codec_is_zero_copy() {
+        let input = RawResource::from("frame");
+        let encoded = IdentityCodec.encode(&input).unwrap();
+        assert_eq!(encoded.as_ptr(), input.as_ptr());
+        let decoded = IdentityCodec.decode(&encoded).unwrap();
+        assert_eq!(decoded, input);
+    }
+
+    #[test]
+    fn test_codecs_are_object_safe() {
+        let encoders: [Box<dyn ResourceEncoder>; 2] =
+            [Box::new(IdentityCodec), Box::new(IdentityCodec)];
+        let decoder: &dyn ResourceDecoder = &IdentityCodec;
+
+        let input = RawResource::from(&[1, 2, 3][..]);
+        let mut encoded = input.clone();
+        for encoder in &encoders {
+            encoded = encoder.encode(&encoded).unwrap();
+        }
+        assert_eq!(decoder.decode(&encoded).unwrap(), input);
+    }
+}
@@ -0,0 +1,52 @@
+//! Timing-safe equality for secret-bearing resources.
+
+use crate::RawResource;
+
+impl RawResource {
+    /// Compares two resources without short-circuiting on the first
+    /// differing byte, for checking MACs, tokens and other secrets.
+    ///
+    /// Only the contents are compared in constant time: resources of
+    /// different lengths are rejected immediately, so length must not be
+    /// secret.
+    ///
+    /// The fallback ORs together the XOR of every byte pair and passes the
+    /// result through [`core::hint::black_box`], but LLVM is still free to
+    /// reintroduce an early exit, so this is best effort. Enable the `subtle`
+    /// feature to delegate to `subtle::ConstantTimeEq` instead.
+    pub fn constant_time_eq(&self, other: &RawResource) -> bool {
+        if self.len() != other.len() {
+            return false;
+        }
+        #[cfg(feature = "subtle")]
+        {
+            use subtle::ConstantTimeEq;
+            self.as_slice().ct_eq(other.as_slice()).into()
+        }
+        #[cfg(not(feature = "subtle"))]
+        {
+            let diff = self
+                .iter()
+                .zip(other.iter())
+                .fold(0u8, |acc, (a, b)| core::hint::black_box(acc | (a ^ b)));
+            diff == 0
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_constant_time_eq() {
+        let tag = RawResource::from(&[0xDE, 0xAD, 0xBE, 0xEF][..]);
+        assert!(tag.constant_time_eq(&tag.deep_clone()));
+        assert!(!tag.constant_time_eq(&RawResource::from(&[0xDE, 0xAD, 0xBE, 0xEE][..])));
+        assert!(!tag.constant_time_eq(&RawResource::from(&[0x5E, 0xAD, 0xBE, 0xEF][..])));
+        assert!(!tag.constant_time_eq(&RawResource::from(&[0xDE, 0xAD][..])));
+
+        let empty = RawResource::from(&[][..]);
+        assert!(empty.constant_time_eq(&RawResource::from("")));
+    }
+}
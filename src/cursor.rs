@@ -0,0 +1,429 @@
+//! Positional reads over a borrowed resource.
+
+use crate::{RawResource, RefinementError};
+
+/// A read cursor over a borrowed [`RawResource`].
+///
+/// Every read advances the position by the number of bytes consumed. A read
+/// that would run past the end fails with [`RefinementError::Exhausted`] and
+/// leaves the position unchanged.
+///
+/// ```
+/// use praborrow_logistics::{RawResource, ResourceCursor};
+///
+/// let packet = RawResource::refine(vec![0x01, 0x00, 0x02, b'h', b'i']).unwrap();
+/// let mut cursor = ResourceCursor::new(&packet);
+/// assert_eq!(cursor.read_u8().unwrap(), 1);
+/// let len = cursor.read_u16_be().unwrap() as usize;
+/// assert_eq!(cursor.read_slice(len).unwrap(), b"hi");
+/// ```
+#[derive(Clone, Debug)]
+pub struct ResourceCursor<'a> {
+    resource: &'a RawResource,
+    pos: usize,
+}
+
+impl<'a> ResourceCursor<'a> {
+    /// Creates a cursor positioned at the start of `resource`.
+    pub fn new(resource: &'a RawResource) -> Self {
+        Self { resource, pos: 0 }
+    }
+
+    /// Returns the current read position.
+    #[inline]
+    pub fn position(&self) -> usize {
+        self.pos
+    }
+
+    /// Returns the number of bytes left to read.
+    #[inline]
+    pub fn remaining(&self) -> usize {
+        self.resource.len().saturating_sub(self.pos)
+    }
+
+    /// Moves the read position to `pos`.
+    ///
+    /// Seeking past the end is allowed; subsequent reads return an error.
+    #[inline]
+    pub fn seek(&mut self, pos: usize) {
+        self.pos = pos;
+    }
+
+    /// Reads the next `n` bytes as a slice borrowed from the resource.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`RefinementError::Exhausted`] if fewer than `n` bytes remain.
+    pub fn read_slice(&mut self, n: usize) -> Result<&'a [u8], RefinementError> {
+        let remaining = self.remaining();
+        if n > remaining {
+            return Err(RefinementError::Exhausted {
+                needed: n,
+                remaining,
+            });
+        }
+        let bytes = &self.resource.as_slice()[self.pos..self.pos + n];
+        self.pos += n;
+        Ok(bytes)
+    }
+
+    /// Copies as many bytes as fit into `buf`, returning how many were read.
+    ///
+    /// Never fails; returns `0` once the cursor reaches the end.
+    pub fn read_available(&mut self, buf: &mut [u8]) -> usize {
+        let n = buf.len().min(self.remaining());
+        if n > 0 {
+            buf[..n].copy_from_slice(&self.resource.as_slice()[self.pos..self.pos + n]);
+            self.pos += n;
+        }
+        n
+    }
+
+    fn read_array<const N: usize>(&mut self) -> Result<[u8; N], RefinementError> {
+        let mut array = [0u8; N];
+        array.copy_from_slice(self.read_slice(N)?);
+        Ok(array)
+    }
+
+    /// Reads a single byte.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`RefinementError::Exhausted`] at the end of the resource.
+    pub fn read_u8(&mut self) -> Result<u8, RefinementError> {
+        self.read_array::<1>().map(|[b]| b)
+    }
+
+    /// Reads a big-endian `u16`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`RefinementError::Exhausted`] if fewer than 2 bytes remain.
+    pub fn read_u16_be(&mut self) -> Result<u16, RefinementError> {
+        self.read_array().map(u16::from_be_bytes)
+    }
+
+    /// Reads a big-endian `u32`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`RefinementError::Exhausted`] if fewer than 4 bytes remain.
+    pub fn read_u32_be(&mut self) -> Result<u32, RefinementError> {
+        self.read_array().map(u32::from_be_bytes)
+    }
+
+    /// Reads a big-endian `u64`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`RefinementError::Exhausted`] if fewer than 8 bytes remain.
+    pub fn read_u64_be(&mut self) -> Result<u64, RefinementError> {
+        self.read_array().map(u64::from_be_bytes)
+    }
+
+    /// Reads a little-endian `u16`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`RefinementError::Exhausted`] if fewer than 2 bytes remain.
+    pub fn read_u16_le(&mut self) -> Result<u16, RefinementError> {
+        self.read_array().map(u16::from_le_bytes)
+    }
+
+    /// Reads a little-endian `u32`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`RefinementError::Exhausted`] if fewer than 4 bytes remain.
+    pub fn read_u32_le(&mut self) -> Result<u32, RefinementError> {
+        self.read_array().map(u32::from_le_bytes)
+    }
+
+    /// Reads a little-endian `u64`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`RefinementError::Exhausted`] if fewer than 8 bytes remain.
+    pub fn read_u64_le(&mut self) -> Result<u64, RefinementError> {
+        self.read_array().map(u64::from_le_bytes)
+    }
+
+    /// Reads a little-endian `i16`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`RefinementError::Exhausted`] if fewer than 2 bytes remain.
+    pub fn read_i16_le(&mut self) -> Result<i16, RefinementError> {
+        self.read_array().map(i16::from_le_bytes)
+    }
+
+    /// Reads a little-endian `i32`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`RefinementError::Exhausted`] if fewer than 4 bytes remain.
+    pub fn read_i32_le(&mut self) -> Result<i32, RefinementError> {
+        self.read_array().map(i32::from_le_bytes)
+    }
+
+    /// Reads a little-endian `i64`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`RefinementError::Exhausted`] if fewer than 8 bytes remain.
+    pub fn read_i64_le(&mut self) -> Result<i64, RefinementError> {
+        self.read_array().map(i64::from_le_bytes)
+    }
+
+    /// Reads an unsigned LEB128 varint, as used by Protocol Buffers.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`RefinementError::Exhausted`] if the resource ends before the
+    /// final byte, or [`RefinementError::MalformedEncoding`] if the varint is
+    /// longer than 10 bytes or does not fit in a `u64`.
+    pub fn read_varint_leb128(&mut self) -> Result<u64, RefinementError> {
+        let start = self.pos.min(self.resource.len());
+        let bytes = &self.resource.as_slice()[start..];
+        let mut value = 0u64;
+        for (i, &byte) in bytes.iter().enumerate() {
+            // The tenth byte may only carry the top bit of a `u64`.
+            if i == 9 && byte > 1 {
+                return Err(RefinementError::MalformedEncoding { offset: self.pos });
+            }
+            value |= u64::from(byte & 0x7F) << (7 * i);
+            if byte & 0x80 == 0 {
+                self.pos += i + 1;
+                return Ok(value);
+            }
+        }
+        Err(RefinementError::Exhausted {
+            needed: bytes.len() + 1,
+            remaining: bytes.len(),
+        })
+    }
+
+    /// Reads a zigzag-encoded signed LEB128 varint (Protocol Buffers `sint64`).
+    ///
+    /// # Errors
+    ///
+    /// Fails like [`read_varint_leb128`](Self::read_varint_leb128).
+    pub fn read_varint_zigzag(&mut self) -> Result<i64, RefinementError> {
+        let v = self.read_varint_leb128()?;
+        Ok((v >> 1) as i64 ^ -((v & 1) as i64))
+    }
+}
+
+#[cfg(feature = "embedded-io")]
+impl embedded_io::ErrorType for ResourceCursor<'_> {
+    type Error = core::convert::Infallible;
+}
+
+/// Reads copy out of the resource; `Ok(0)` signals the end of the buffer.
+#[cfg(feature = "embedded-io")]
+impl embedded_io::Read for ResourceCursor<'_> {
+    fn read(&mut self, buf: &mut [u8]) -> Result<usize, Self::Error> {
+        Ok(self.read_available(buf))
+    }
+}
+
+/// Reads copy out of the resource; `Ok(0)` signals the end of the buffer.
+#[cfg(feature = "std")]
+impl std::io::Read for ResourceCursor<'_> {
+    fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+        Ok(self.read_available(buf))
+    }
+}
+
+/// Seeking past the end is allowed, as with `std::io::Cursor`; seeking before
+/// the start is an `InvalidInput` error. The inherent [`ResourceCursor::seek`]
+/// takes precedence in method syntax, so call this as `Seek::seek(&mut c, ..)`.
+#[cfg(feature = "std")]
+impl std::io::Seek for ResourceCursor<'_> {
+    fn seek(&mut self, pos: std::io::SeekFrom) -> std::io::Result<u64> {
+        use std::io::{Error, ErrorKind, SeekFrom};
+
+        let (base, offset) = match pos {
+            SeekFrom::Start(n) => (n, 0),
+            SeekFrom::End(n) => (self.resource.len() as u64, n),
+            SeekFrom::Current(n) => (self.pos as u64, n),
+        };
+        let target = base
+            .checked_add_signed(offset)
+            .and_then(|target| usize::try_from(target).ok())
+            .ok_or_else(|| Error::new(ErrorKind::InvalidInput, "invalid seek position"))?;
+        self.pos = target;
+        Ok(target as u64)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample() -> RawResource {
+        RawResource::refine((1..=15).collect()).expect("should succeed")
+    }
+
+    #[test]
+    fn test_big_endian_reads() {
+        let resource = sample();
+        let mut cursor = ResourceCursor::new(&resource);
+        assert_eq!(cursor.read_u8(), Ok(0x01));
+        assert_eq!(cursor.read_u16_be(), Ok(0x0203));
+        assert_eq!(cursor.read_u32_be(), Ok(0x0405_0607));
+        assert_eq!(cursor.read_u64_be(), Ok(0x0809_0A0B_0C0D_0E0F));
+        assert_eq!(cursor.remaining(), 0);
+    }
+
+    #[test]
+    fn test_little_endian_reads() {
+        let resource = RawResource::from(&[0x02, 0x01, 0xFE, 0xFF, 0xFF, 0xFF][..]);
+        let mut cursor = ResourceCursor::new(&resource);
+        assert_eq!(cursor.read_u16_le(), Ok(0x0102));
+        assert_eq!(cursor.read_i32_le(), Ok(-2));
+    }
+
+    #[test]
+    fn test_little_endian_round_trip() {
+        use crate::ResourceBuilder;
+
+        let mut builder = ResourceBuilder::new();
+        builder.push_u16_le(0xBEEF);
+        builder.push_u32_le(0xDEAD_BEEF);
+        builder.push_u64_le(u64::MAX - 1);
+        builder.push_slice(&(-1234i16).to_le_bytes());
+        builder.push_slice(&i32::MIN.to_le_bytes());
+        builder.push_slice(&(-42i64).to_le_bytes());
+        let resource = builder.build().expect("should succeed");
+
+        let mut cursor = ResourceCursor::new(&resource);
+        assert_eq!(cursor.read_u16_le(), Ok(0xBEEF));
+        assert_eq!(cursor.read_u32_le(), Ok(0xDEAD_BEEF));
+        assert_eq!(cursor.read_u64_le(), Ok(u64::MAX - 1));
+        assert_eq!(cursor.read_i16_le(), Ok(-1234));
+        assert_eq!(cursor.read_i32_le(), Ok(i32::MIN));
+        assert_eq!(cursor.read_i64_le(), Ok(-42));
+        assert_eq!(cursor.remaining(), 0);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_std_read_and_seek() {
+        use std::io::{Read, Seek, SeekFrom};
+
+        let resource = sample();
+        let mut cursor = ResourceCursor::new(&resource);
+        assert_eq!(
+            Seek::seek(&mut cursor, SeekFrom::End(-3)).expect("in range"),
+            12
+        );
+        let mut tail = std::vec::Vec::new();
+        cursor.read_to_end(&mut tail).expect("infallible");
+        assert_eq!(tail, [13, 14, 15]);
+
+        assert_eq!(
+            Seek::seek(&mut cursor, SeekFrom::Current(-15)).expect("in range"),
+            0
+        );
+        let mut head = [0u8; 2];
+        cursor.read_exact(&mut head).expect("enough bytes");
+        assert_eq!(head, [1, 2]);
+        assert!(Seek::seek(&mut cursor, SeekFrom::Current(-3)).is_err());
+        assert_eq!(cursor.position(), 2);
+    }
+
+    #[test]
+    fn test_read_available_is_partial() {
+        let resource = sample();
+        let mut cursor = ResourceCursor::new(&resource);
+        cursor.seek(13);
+        let mut buf = [0u8; 4];
+        assert_eq!(cursor.read_available(&mut buf), 2);
+        assert_eq!(&buf[..2], &[14, 15]);
+        assert_eq!(cursor.read_available(&mut buf), 0);
+    }
+
+    #[test]
+    fn test_read_past_end_leaves_position() {
+        let resource = sample();
+        let mut cursor = ResourceCursor::new(&resource);
+        cursor.seek(12);
+        assert_eq!(
+            cursor.read_u32_be(),
+            Err(RefinementError::Exhausted {
+                needed: 4,
+                remaining: 3
+            })
+        );
+        assert_eq!(cursor.position(), 12);
+        assert_eq!(cursor.read_slice(3), Ok(&[13, 14, 15][..]));
+    }
+
+    #[test]
+    fn test_seek_beyond_end() {
+        let resource = sample();
+        let mut cursor = ResourceCursor::new(&resource);
+        cursor.seek(100);
+        assert_eq!(cursor.remaining(), 0);
+        assert!(cursor.read_u8().is_err());
+        cursor.seek(0);
+        assert_eq!(cursor.read_u8(), Ok(1));
+    }
+
+    #[test]
+    fn test_read_varint_protobuf_vectors() {
+        let resource = RawResource::from(&[0x01, 0x96, 0x01, 0xAC, 0x02, 0x00][..]);
+        let mut cursor = ResourceCursor::new(&resource);
+        assert_eq!(cursor.read_varint_leb128(), Ok(1));
+        assert_eq!(cursor.read_varint_leb128(), Ok(150));
+        assert_eq!(cursor.read_varint_leb128(), Ok(300));
+        assert_eq!(cursor.read_varint_leb128(), Ok(0));
+        assert_eq!(cursor.remaining(), 0);
+
+        let max =
+            RawResource::from(&[0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0x01][..]);
+        assert_eq!(ResourceCursor::new(&max).read_varint_leb128(), Ok(u64::MAX));
+
+        // sint32 vectors: 0, -1, 1, -2, 2147483647, -2147483648.
+        let zigzag = RawResource::from(
+            &[
+                0x00, 0x01, 0x02, 0x03, 0xFE, 0xFF, 0xFF, 0xFF, 0x0F, 0xFF, 0xFF, 0xFF, 0xFF, 0x0F,
+            ][..],
+        );
+        let mut cursor = ResourceCursor::new(&zigzag);
+        for expected in [0, -1, 1, -2, 2_147_483_647, -2_147_483_648] {
+            assert_eq!(cursor.read_varint_zigzag(), Ok(expected));
+        }
+    }
+
+    #[test]
+    fn test_read_varint_errors_leave_position() {
+        let truncated = RawResource::from(&[0x00, 0x96][..]);
+        let mut cursor = ResourceCursor::new(&truncated);
+        assert_eq!(cursor.read_varint_leb128(), Ok(0));
+        assert_eq!(
+            cursor.read_varint_leb128(),
+            Err(RefinementError::Exhausted {
+                needed: 2,
+                remaining: 1
+            })
+        );
+        assert_eq!(cursor.position(), 1);
+
+        let overflow =
+            RawResource::from(&[0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0x02][..]);
+        let mut cursor = ResourceCursor::new(&overflow);
+        assert_eq!(
+            cursor.read_varint_leb128(),
+            Err(RefinementError::MalformedEncoding { offset: 0 })
+        );
+        assert_eq!(cursor.position(), 0);
+
+        let overlong = RawResource::from(&[0x80; 11][..]);
+        assert_eq!(
+            ResourceCursor::new(&overlong).read_varint_leb128(),
+            Err(RefinementError::MalformedEncoding { offset: 0 })
+        );
+    }
+}
@@ -0,0 +1,147 @@
+//! Byte-level differences between equal-length resources.
+
+use alloc::vec::Vec;
+
+use crate::{RawResource, RefinementError};
+
+/// The positions at which two equal-length resources differ.
+///
+/// Each change is an `(offset, byte)` pair holding the byte from the newer
+/// resource. Applying the diff to a copy of the older resource reproduces the
+/// newer one, so only the changed bytes need to be sent to a remote peer.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct ResourceDiff {
+    len: usize,
+    changes: Vec<(usize, u8)>,
+}
+
+impl ResourceDiff {
+    /// Records every offset at which `b` differs from `a`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`RefinementError::LengthMismatch`] if `a` and `b` have
+    /// different lengths.
+    pub fn compute(a: &RawResource, b: &RawResource) -> Result<Self, RefinementError> {
+        if a.len() != b.len() {
+            return Err(RefinementError::LengthMismatch {
+                expected: a.len(),
+                actual: b.len(),
+            });
+        }
+        let changes = a
+            .iter()
+            .zip(b.iter())
+            .enumerate()
+            .filter(|(_, (old, new))| old != new)
+            .map(|(offset, (_, &new))| (offset, new))
+            .collect();
+        Ok(Self {
+            len: a.len(),
+            changes,
+        })
+    }
+
+    /// Writes every recorded byte into `target`.
+    ///
+    /// Copies the bytes first if `target`'s buffer is shared.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`RefinementError::LengthMismatch`] if `target` is not the
+    /// length the diff was computed for; `target` is left unchanged.
+    pub fn apply(&self, target: &mut RawResource) -> Result<(), RefinementError> {
+        if target.len() != self.len {
+            return Err(RefinementError::LengthMismatch {
+                expected: self.len,
+                actual: target.len(),
+            });
+        }
+        if self.changes.is_empty() {
+            return Ok(());
+        }
+        target.modify(|buf| {
+            for &(offset, byte) in &self.changes {
+                buf[offset] = byte;
+            }
+        });
+        Ok(())
+    }
+
+    /// Returns the `(offset, byte)` pairs in ascending offset order.
+    #[inline]
+    pub fn changes(&self) -> &[(usize, u8)] {
+        &self.changes
+    }
+
+    /// Returns the number of differing bytes.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.changes.len()
+    }
+
+    /// Returns `true` if the resources were identical.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.changes.is_empty()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_diff_identical() {
+        let a = RawResource::from(&[1, 2, 3][..]);
+        let diff = ResourceDiff::compute(&a, &a.deep_clone()).unwrap();
+        assert!(diff.is_empty());
+
+        let mut target = a.clone();
+        diff.apply(&mut target).unwrap();
+        assert_eq!(target, a);
+    }
+
+    #[test]
+    fn test_diff_one_change() {
+        let a = RawResource::from(&[1, 2, 3][..]);
+        let b = RawResource::from(&[1, 9, 3][..]);
+        let diff = ResourceDiff::compute(&a, &b).unwrap();
+        assert_eq!(diff.changes(), &[(1, 9)]);
+
+        let mut target = a.clone();
+        diff.apply(&mut target).unwrap();
+        assert_eq!(target, b);
+        assert_eq!(a.as_slice(), &[1, 2, 3]);
+    }
+
+    #[test]
+    fn test_diff_all_different_and_mismatch() {
+        let a = RawResource::from(&[0; 4][..]);
+        let b = RawResource::from(&[0xFF; 4][..]);
+        let diff = ResourceDiff::compute(&a, &b).unwrap();
+        assert_eq!(diff.len(), 4);
+
+        let mut target = a.clone();
+        diff.apply(&mut target).unwrap();
+        assert_eq!(target, b);
+
+        let short = RawResource::from(&[0; 3][..]);
+        assert_eq!(
+            ResourceDiff::compute(&a, &short),
+            Err(RefinementError::LengthMismatch {
+                expected: 4,
+                actual: 3
+            })
+        );
+        let mut short_target = short.clone();
+        assert_eq!(
+            diff.apply(&mut short_target),
+            Err(RefinementError::LengthMismatch {
+                expected: 4,
+                actual: 3
+            })
+        );
+        assert_eq!(short_target, short);
+    }
+}
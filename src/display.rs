@@ -0,0 +1,129 @@
+//! `core::fmt` implementations shared by the resource types.
+
+use core::fmt;
+
+use crate::{MutRawResource, RawResource};
+
+/// Maximum number of bytes rendered by the `Debug` implementations.
+const DEBUG_PREVIEW_LEN: usize = 64;
+
+/// Bytes per row in the `Display` hex dump.
+const DUMP_ROW_LEN: usize = 16;
+
+/// Renders up to [`DEBUG_PREVIEW_LEN`] bytes as a quoted hex string.
+struct HexPreview<'a>(&'a [u8]);
+
+impl fmt::Debug for HexPreview<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let shown = &self.0[..self.0.len().min(DEBUG_PREVIEW_LEN)];
+        f.write_str("\"")?;
+        for byte in shown {
+            write!(f, "{byte:02x}")?;
+        }
+        if shown.len() < self.0.len() {
+            f.write_str("...")?;
+        }
+        f.write_str("\"")
+    }
+}
+
+pub(crate) fn debug_resource(name: &str, bytes: &[u8], f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    f.debug_struct(name)
+        .field("len", &bytes.len())
+        .field("data", &HexPreview(bytes))
+        .finish()
+}
+
+/// Writes an `xxd`-style dump: offset, grouped hex columns, then ASCII.
+fn hex_dump(bytes: &[u8], f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    for (row, chunk) in bytes.chunks(DUMP_ROW_LEN).enumerate() {
+        if row > 0 {
+            f.write_str("\n")?;
+        }
+        write!(f, "{:08x}:", row * DUMP_ROW_LEN)?;
+        for i in 0..DUMP_ROW_LEN {
+            if i % 2 == 0 {
+                f.write_str(" ")?;
+            }
+            match chunk.get(i) {
+                Some(byte) => write!(f, "{byte:02x}")?,
+                None => f.write_str("  ")?,
+            }
+        }
+        f.write_str("  ")?;
+        for &byte in chunk {
+            let c = if byte.is_ascii_graphic() || byte == b' ' {
+                byte as char
+            } else {
+                '.'
+            };
+            write!(f, "{c}")?;
+        }
+    }
+    Ok(())
+}
+
+impl fmt::Debug for RawResource {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        debug_resource("RawResource", self.as_slice(), f)
+    }
+}
+
+impl fmt::Display for RawResource {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        hex_dump(self.as_slice(), f)
+    }
+}
+
+impl fmt::Debug for MutRawResource {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        debug_resource("MutRawResource", self.as_slice(), f)
+    }
+}
+
+impl fmt::Display for MutRawResource {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        hex_dump(self.as_slice(), f)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::format;
+
+    #[test]
+    fn test_debug_shows_len_and_hex() {
+        let resource = RawResource::refine(alloc::vec![0x0a, 0x1b, 0x2c]).expect("should succeed");
+        assert_eq!(
+            format!("{resource:?}"),
+            "RawResource { len: 3, data: \"0a1b2c\" }"
+        );
+    }
+
+    #[test]
+    fn test_debug_truncates_long_buffers() {
+        let resource = RawResource::refine(alloc::vec![0xAB; 1024]).expect("should succeed");
+        let expected = format!(
+            "RawResource {{ len: 1024, data: \"{}...\" }}",
+            "ab".repeat(64)
+        );
+        assert_eq!(format!("{resource:?}"), expected);
+    }
+
+    #[test]
+    fn test_display_hex_dump() {
+        let resource = RawResource::refine(b"Hello, World!\n\x00\x01Second row".to_vec())
+            .expect("should succeed");
+        let expected = "\
+00000000: 4865 6c6c 6f2c 2057 6f72 6c64 210a 0001  Hello, World!...
+00000010: 5365 636f 6e64 2072 6f77                 Second row";
+        assert_eq!(format!("{resource}"), expected);
+    }
+
+    #[test]
+    fn test_display_empty() {
+        let resource = RawResource::refine(alloc::vec![]).expect("should succeed");
+        assert_eq!(format!("{resource}"), "");
+    }
+}
@@ -0,0 +1,164 @@
+//! Big-endian length-prefixed framing.
+
+use alloc::vec::Vec;
+
+use crate::{RawResource, RefinementError, ResourceView};
+
+/// Width of the big-endian length field in front of a frame.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum FramePrefixLen {
+    /// A 1-byte length, for payloads up to 255 bytes.
+    U8,
+    /// A 2-byte length, for payloads up to 65535 bytes.
+    U16,
+    /// A 4-byte length, for payloads up to 4 GiB - 1.
+    U32,
+}
+
+impl FramePrefixLen {
+    /// Returns the width of the length field in bytes.
+    #[inline]
+    pub const fn width(self) -> usize {
+        match self {
+            Self::U8 => 1,
+            Self::U16 => 2,
+            Self::U32 => 4,
+        }
+    }
+
+    /// Returns the largest payload length the field can represent.
+    #[inline]
+    pub const fn max_payload(self) -> u64 {
+        match self {
+            Self::U8 => u8::MAX as u64,
+            Self::U16 => u16::MAX as u64,
+            Self::U32 => u32::MAX as u64,
+        }
+    }
+}
+
+impl RawResource {
+    /// Returns a new resource holding a big-endian length field followed by
+    /// these bytes.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`RefinementError::CapacityOverflow`] if `len()` does not fit
+    /// in `prefix_len`.
+    pub fn frame_encode(&self, prefix_len: FramePrefixLen) -> Result<Self, RefinementError> {
+        let len = u64::try_from(self.len())
+            .ok()
+            .filter(|&len| len <= prefix_len.max_payload())
+            .ok_or(RefinementError::CapacityOverflow)?;
+        let width = prefix_len.width();
+        let mut framed = Vec::with_capacity(width + self.len());
+        framed.extend_from_slice(&len.to_be_bytes()[8 - width..]);
+        framed.extend_from_slice(self.as_slice());
+        Self::refine(framed)
+    }
+
+    /// Splits the first frame off `data`.
+    ///
+    /// Returns the payload as a zero-copy resource sharing `data`'s buffer,
+    /// together with a view of any bytes after the frame, so consecutive
+    /// frames in a stream can be decoded in a loop.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`RefinementError::Exhausted`] if `data` is shorter than the
+    /// length field or the payload it announces.
+    pub fn frame_decode(
+        data: &RawResource,
+        prefix_len: FramePrefixLen,
+    ) -> Result<(RawResource, ResourceView<'_>), RefinementError> {
+        let width = prefix_len.width();
+        let header = data.get_slice(0..width).ok_or(RefinementError::Exhausted {
+            needed: width,
+            remaining: data.len(),
+        })?;
+        let mut len = [0u8; 8];
+        len[8 - width..].copy_from_slice(header);
+        // Saturate on 16-bit targets; such a length can never be satisfied.
+        let payload_len = usize::try_from(u64::from_be_bytes(len)).unwrap_or(usize::MAX);
+        let remaining = data.len() - width;
+        if payload_len > remaining {
+            return Err(RefinementError::Exhausted {
+                needed: payload_len,
+                remaining,
+            });
+        }
+        let end = width + payload_len;
+        let payload = RawResource::from_bytes(data.inner.slice(width..end));
+        Ok((payload, ResourceView::new(&data.as_slice()[end..])))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_frame_encode_prefixes() {
+        let payload = RawResource::from(&[0xAA, 0xBB, 0xCC][..]);
+        let cases: [(FramePrefixLen, &[u8]); 3] = [
+            (FramePrefixLen::U8, &[3]),
+            (FramePrefixLen::U16, &[0, 3]),
+            (FramePrefixLen::U32, &[0, 0, 0, 3]),
+        ];
+        for (prefix_len, header) in cases {
+            let framed = payload.frame_encode(prefix_len).unwrap();
+            assert_eq!(&framed[..header.len()], header);
+            assert_eq!(&framed[header.len()..], payload.as_slice());
+
+            let (decoded, rest) = RawResource::frame_decode(&framed, prefix_len).unwrap();
+            assert_eq!(decoded, payload);
+            assert!(rest.is_empty());
+        }
+    }
+
+    #[test]
+    fn test_frame_decode_stream() {
+        let first = RawResource::from("hi")
+            .frame_encode(FramePrefixLen::U16)
+            .unwrap();
+        let second = RawResource::from("")
+            .frame_encode(FramePrefixLen::U16)
+            .unwrap();
+        let stream = crate::concat([first, second]).unwrap();
+
+        let (payload, rest) = RawResource::frame_decode(&stream, FramePrefixLen::U16).unwrap();
+        assert_eq!(payload.as_slice(), b"hi");
+        assert_eq!(payload.as_ptr(), stream[2..].as_ptr());
+        assert_eq!(rest.as_slice(), &[0, 0]);
+
+        let rest = RawResource::from(rest.as_slice());
+        let (payload, tail) = RawResource::frame_decode(&rest, FramePrefixLen::U16).unwrap();
+        assert!(payload.is_empty());
+        assert!(tail.is_empty());
+    }
+
+    #[test]
+    fn test_frame_errors() {
+        let too_long = RawResource::from(&[0u8; 256][..]);
+        assert_eq!(
+            too_long.frame_encode(FramePrefixLen::U8),
+            Err(RefinementError::CapacityOverflow)
+        );
+        assert!(too_long.frame_encode(FramePrefixLen::U16).is_ok());
+
+        assert_eq!(
+            RawResource::frame_decode(&RawResource::from(&[0][..]), FramePrefixLen::U16),
+            Err(RefinementError::Exhausted {
+                needed: 2,
+                remaining: 1
+            })
+        );
+        assert_eq!(
+            RawResource::frame_decode(&RawResource::from(&[5, 1, 2][..]), FramePrefixLen::U8),
+            Err(RefinementError::Exhausted {
+                needed: 5,
+                remaining: 2
+            })
+        );
+    }
+}
@@ -0,0 +1,97 @@
+//! Streaming FNV-1a hashing of resource contents.
+
+use core::hash::Hasher;
+
+use crate::{RawResource, ResourceView};
+
+const FNV_OFFSET_BASIS: u64 = 0xcbf2_9ce4_8422_2325;
+const FNV_PRIME: u64 = 0x0000_0100_0000_01b3;
+
+/// A 64-bit FNV-1a hasher.
+///
+/// FNV-1a folds in one byte at a time, so feeding the same bytes in any
+/// chunking produces the same hash. That makes it suitable for hashing
+/// payloads as they stream in, or for comparing chunks during
+/// deduplication. It is fast but not collision-resistant; do not use it
+/// where an attacker controls the input.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ResourceHasher {
+    state: u64,
+}
+
+impl ResourceHasher {
+    /// Creates a hasher in the FNV-1a initial state.
+    pub const fn new() -> Self {
+        Self {
+            state: FNV_OFFSET_BASIS,
+        }
+    }
+
+    /// Feeds the bytes of `view` into the hash.
+    #[inline]
+    pub fn update_with_view(&mut self, view: ResourceView<'_>) {
+        self.write(view.as_slice());
+    }
+}
+
+impl Default for ResourceHasher {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Hasher for ResourceHasher {
+    #[inline]
+    fn finish(&self) -> u64 {
+        self.state
+    }
+
+    #[inline]
+    fn write(&mut self, bytes: &[u8]) {
+        for &byte in bytes {
+            self.state ^= u64::from(byte);
+            self.state = self.state.wrapping_mul(FNV_PRIME);
+        }
+    }
+}
+
+/// Returns the 64-bit FNV-1a hash of the bytes in `resource`.
+///
+/// Unlike `Hash::hash`, no length prefix is mixed in, so the result matches
+/// the published FNV-1a test vectors.
+pub fn hash_resource(resource: &RawResource) -> u64 {
+    let mut hasher = ResourceHasher::new();
+    hasher.write(resource.as_slice());
+    hasher.finish()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_fnv1a_vectors() {
+        assert_eq!(hash_resource(&RawResource::from("")), 0xcbf2_9ce4_8422_2325);
+        assert_eq!(
+            hash_resource(&RawResource::from("a")),
+            0xaf63_dc4c_8601_ec8c
+        );
+        assert_eq!(
+            hash_resource(&RawResource::from("foobar")),
+            0x8594_4171_f739_67e8
+        );
+    }
+
+    #[test]
+    fn test_chunking_does_not_change_hash() {
+        let resource: RawResource = (0..=255u8).cycle().take(1000).collect();
+        let expected = hash_resource(&resource);
+        for chunk_size in [1, 3, 64, 999, 1000] {
+            let mut hasher = ResourceHasher::new();
+            for chunk in resource.chunks(chunk_size) {
+                hasher.update_with_view(chunk);
+            }
+            assert_eq!(hasher.finish(), expected, "chunk size {chunk_size}");
+        }
+    }
+}
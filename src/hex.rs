@@ -0,0 +1,177 @@
+//! Hexadecimal encoding and decoding, implemented without dependencies.
+
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::fmt;
+
+use crate::RawResource;
+
+/// Error returned when decoding a hex string.
+#[derive(thiserror::Error, Debug, Clone, PartialEq, Eq)]
+pub enum HexError {
+    /// The input has an odd number of digits, so the last byte is incomplete.
+    #[error("hex string has odd length {len}")]
+    OddLength {
+        /// Length of the input in bytes.
+        len: usize,
+    },
+    /// The input contains a character that is not a hex digit.
+    #[error("invalid hex character {character:?} at index {index}")]
+    InvalidCharacter {
+        /// The offending character.
+        character: char,
+        /// Byte offset of the character in the input.
+        index: usize,
+    },
+}
+
+/// Formats bytes as lowercase hex without allocating.
+#[cfg(feature = "serde")]
+pub(crate) struct HexDisplay<'a>(pub(crate) &'a [u8]);
+
+#[cfg(feature = "serde")]
+impl fmt::Display for HexDisplay<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.0.iter().try_for_each(|byte| write!(f, "{byte:02x}"))
+    }
+}
+
+fn digit(c: u8) -> Option<u8> {
+    match c {
+        b'0'..=b'9' => Some(c - b'0'),
+        b'a'..=b'f' => Some(c - b'a' + 10),
+        b'A'..=b'F' => Some(c - b'A' + 10),
+        _ => None,
+    }
+}
+
+/// Decodes pairs of ASCII hex digits (either case) into bytes.
+pub(crate) fn decode(s: &str) -> Result<Vec<u8>, HexError> {
+    let bytes = s.as_bytes();
+    if !bytes.len().is_multiple_of(2) {
+        return Err(HexError::OddLength { len: bytes.len() });
+    }
+    let nibble = |index: usize| {
+        digit(bytes[index]).ok_or_else(|| HexError::InvalidCharacter {
+            // Every byte before `index` is an ASCII digit, so `index` is a
+            // character boundary.
+            character: s[index..].chars().next().unwrap_or('\0'),
+            index,
+        })
+    };
+    (0..bytes.len())
+        .step_by(2)
+        .map(|i| Ok((nibble(i)? << 4) | nibble(i + 1)?))
+        .collect()
+}
+
+impl RawResource {
+    /// Decodes a hex string such as `"deadbeef"` into a new resource.
+    ///
+    /// Accepts upper- and lower-case digits; an empty string yields an empty
+    /// resource.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`HexError::OddLength`] or [`HexError::InvalidCharacter`] if
+    /// `s` is not a sequence of hex digit pairs.
+    pub fn refine_from_hex(s: &str) -> Result<Self, HexError> {
+        let data = decode(s)?;
+        Ok(Self::refine(data).expect("refine is infallible"))
+    }
+
+    /// Encodes the bytes as a lowercase hex string, the inverse of
+    /// [`refine_from_hex`](Self::refine_from_hex).
+    pub fn as_hex_string(&self) -> String {
+        alloc::format!("{self:x}")
+    }
+
+    /// Encodes the bytes as an uppercase hex string.
+    pub fn as_upper_hex_string(&self) -> String {
+        alloc::format!("{self:X}")
+    }
+}
+
+/// Formats the bytes as lowercase hex; the `#` flag adds a `0x` prefix.
+impl fmt::LowerHex for RawResource {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if f.alternate() {
+            f.write_str("0x")?;
+        }
+        self.iter().try_for_each(|byte| write!(f, "{byte:02x}"))
+    }
+}
+
+/// Formats the bytes as uppercase hex; the `#` flag adds a `0x` prefix.
+impl fmt::UpperHex for RawResource {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if f.alternate() {
+            f.write_str("0x")?;
+        }
+        self.iter().try_for_each(|byte| write!(f, "{byte:02X}"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_refine_from_hex_both_cases() {
+        let lower = RawResource::refine_from_hex("deadbeef").expect("valid hex");
+        let upper = RawResource::refine_from_hex("DEADBEEF").expect("valid hex");
+        let mixed = RawResource::refine_from_hex("DeAdBeEf").expect("valid hex");
+        assert_eq!(lower.as_slice(), &[0xDE, 0xAD, 0xBE, 0xEF]);
+        assert_eq!(upper, lower);
+        assert_eq!(mixed, lower);
+        assert!(
+            RawResource::refine_from_hex("")
+                .expect("valid hex")
+                .is_empty()
+        );
+    }
+
+    #[test]
+    fn test_as_hex_string() {
+        let resource = RawResource::from(&[0x00, 0x0f, 0xab, 0xff][..]);
+        assert_eq!(resource.as_hex_string(), "000fabff");
+        assert_eq!(resource.as_upper_hex_string(), "000FABFF");
+        assert_eq!(alloc::format!("{resource:x}"), "000fabff");
+        assert_eq!(alloc::format!("{resource:X}"), "000FABFF");
+        assert_eq!(RawResource::from(&[][..]).as_hex_string(), "");
+    }
+
+    #[test]
+    fn test_hex_format_alternate_and_round_trip() {
+        let resource = RawResource::from(&[0xde, 0xad, 0xbe, 0xef][..]);
+        assert_eq!(alloc::format!("{resource:#x}"), "0xdeadbeef");
+        assert_eq!(alloc::format!("{resource:#X}"), "0xDEADBEEF");
+
+        let lower = alloc::format!("{resource:x}");
+        let upper = alloc::format!("{resource:X}");
+        assert_eq!(RawResource::refine_from_hex(&lower), Ok(resource.clone()));
+        assert_eq!(RawResource::refine_from_hex(&upper), Ok(resource));
+    }
+
+    #[test]
+    fn test_refine_from_hex_errors() {
+        assert_eq!(
+            RawResource::refine_from_hex("abc"),
+            Err(HexError::OddLength { len: 3 })
+        );
+        assert_eq!(
+            RawResource::refine_from_hex("0g"),
+            Err(HexError::InvalidCharacter {
+                character: 'g',
+                index: 1
+            })
+        );
+        assert_eq!(
+            RawResource::refine_from_hex("00é"),
+            Err(HexError::InvalidCharacter {
+                character: 'é',
+                index: 2
+            })
+        );
+    }
+}
@@ -0,0 +1,63 @@
+//! Identifiers for correlating resources with requests and frames.
+
+#[cfg(target_has_atomic = "64")]
+use core::sync::atomic::{AtomicU64, Ordering};
+
+use crate::ResourceAnnotated;
+
+/// An opaque 64-bit identifier for a resource.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct ResourceId(u64);
+
+impl ResourceId {
+    /// Returns an identifier not returned by any earlier call in this
+    /// process.
+    ///
+    /// Backed by a global atomic counter starting at 1, so it works without
+    /// `std`; it is only available on targets with 64-bit atomics. IDs built
+    /// with [`from_u64`](Self::from_u64) may collide with these.
+    #[cfg(target_has_atomic = "64")]
+    pub fn new_unique() -> Self {
+        static NEXT: AtomicU64 = AtomicU64::new(1);
+        Self(NEXT.fetch_add(1, Ordering::Relaxed))
+    }
+
+    /// Wraps an existing raw identifier, e.g. one received from a peer.
+    #[inline]
+    pub const fn from_u64(value: u64) -> Self {
+        Self(value)
+    }
+
+    /// Returns the raw identifier.
+    #[inline]
+    pub const fn as_u64(&self) -> u64 {
+        self.0
+    }
+}
+
+/// A resource tagged with a [`ResourceId`].
+pub type IdentifiedResource = ResourceAnnotated<ResourceId>;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::RawResource;
+
+    #[test]
+    fn test_new_unique_ids_differ() {
+        let a = ResourceId::new_unique();
+        let b = ResourceId::new_unique();
+        assert_ne!(a, b);
+        assert!(b > a);
+    }
+
+    #[test]
+    fn test_raw_round_trip_and_alias() {
+        let id = ResourceId::from_u64(42);
+        assert_eq!(id.as_u64(), 42);
+
+        let tagged = IdentifiedResource::new(RawResource::from("frame"), id);
+        assert_eq!(*tagged.metadata(), ResourceId::from_u64(42));
+        assert_eq!(tagged.as_slice(), b"frame");
+    }
+}
@@ -0,0 +1,145 @@
+//! Byte iterators over resources.
+
+use bytes::Bytes;
+
+use crate::RawResource;
+
+/// An owning iterator over the bytes of a [`RawResource`].
+///
+/// Created by `RawResource::into_iter`. Holds the buffer until the iterator
+/// is dropped, so stopping early still frees it.
+#[derive(Debug)]
+pub struct IntoIter {
+    inner: bytes::buf::IntoIter<Bytes>,
+}
+
+impl Iterator for IntoIter {
+    type Item = u8;
+
+    #[inline]
+    fn next(&mut self) -> Option<u8> {
+        self.inner.next()
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
+}
+
+impl ExactSizeIterator for IntoIter {}
+
+impl RawResource {
+    /// Iterates over copies of the bytes without consuming the resource.
+    #[inline]
+    pub fn byte_iter(&self) -> impl DoubleEndedIterator<Item = u8> + ExactSizeIterator + '_ {
+        self.as_slice().iter().copied()
+    }
+
+    /// Iterates over `(offset, byte)` pairs.
+    #[inline]
+    pub fn enumerate_bytes(
+        &self,
+    ) -> impl DoubleEndedIterator<Item = (usize, u8)> + ExactSizeIterator + '_ {
+        self.byte_iter().enumerate()
+    }
+
+    /// Folds over every byte with a plain loop over the contiguous buffer.
+    ///
+    /// Avoids iterator adaptor layers so the loop body is all the optimizer
+    /// sees, which gives it the best chance to unroll or vectorize. A slice
+    /// loop already compiles to pointer increments, so no `unsafe` is needed.
+    #[inline]
+    pub fn fold_bytes<B>(&self, init: B, mut f: impl FnMut(B, u8) -> B) -> B {
+        let mut acc = init;
+        for &byte in self.as_slice() {
+            acc = f(acc, byte);
+        }
+        acc
+    }
+}
+
+impl IntoIterator for RawResource {
+    type Item = u8;
+    type IntoIter = IntoIter;
+
+    fn into_iter(self) -> IntoIter {
+        IntoIter {
+            inner: self.into_bytes().into_iter(),
+        }
+    }
+}
+
+impl<'a> IntoIterator for &'a RawResource {
+    type Item = &'a u8;
+    type IntoIter = core::slice::Iter<'a, u8>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.as_slice().iter()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_into_iter_yields_bytes() {
+        let resource = RawResource::refine(alloc::vec![1, 2, 3]).expect("should succeed");
+        let mut sum = 0;
+        for byte in resource {
+            sum += byte;
+        }
+        assert_eq!(sum, 6);
+    }
+
+    #[test]
+    fn test_into_iter_partial_then_drop() {
+        let resource = RawResource::refine(alloc::vec![9; 16]).expect("should succeed");
+        let mut iter = resource.into_iter();
+        assert_eq!(iter.len(), 16);
+        assert_eq!(iter.next(), Some(9));
+        assert_eq!(iter.len(), 15);
+        drop(iter);
+    }
+
+    #[test]
+    fn test_byte_iter() {
+        let resource = RawResource::refine(alloc::vec![1, 2, 3, 4]).expect("should succeed");
+        assert_eq!(resource.byte_iter().len(), 4);
+        assert!(resource.byte_iter().rev().eq([4, 3, 2, 1]));
+        assert_eq!(resource.byte_iter().nth(2), Some(3));
+        assert_eq!(resource.byte_iter().count(), 4);
+    }
+
+    #[test]
+    fn test_enumerate_bytes() {
+        let resource = RawResource::from("xyz");
+        assert!(
+            resource
+                .enumerate_bytes()
+                .eq([(0, b'x'), (1, b'y'), (2, b'z')])
+        );
+        assert_eq!(resource.enumerate_bytes().next_back(), Some((2, b'z')));
+        assert_eq!(resource.enumerate_bytes().len(), 3);
+    }
+
+    #[test]
+    fn test_fold_bytes_matches_iterator_fold() {
+        let resource = RawResource::refine((0..=255).collect()).expect("should succeed");
+        let expected = resource.byte_iter().fold(0u32, |acc, b| acc + u32::from(b));
+        assert_eq!(
+            resource.fold_bytes(0u32, |acc, b| acc + u32::from(b)),
+            expected
+        );
+        assert_eq!(RawResource::from("").fold_bytes(7, |acc, _| acc + 1), 7);
+    }
+
+    #[test]
+    fn test_borrowed_into_iter() {
+        let resource = RawResource::from("ab");
+        let collected: alloc::vec::Vec<u8> = (&resource).into_iter().copied().collect();
+        assert_eq!(collected, b"ab");
+        assert_eq!(resource.len(), 2);
+    }
+}
@@ -0,0 +1,93 @@
+//! Deferred construction of a resource until it is first needed.
+
+use alloc::vec::Vec;
+
+use crate::RawResource;
+
+/// A resource whose bytes are produced on first access.
+///
+/// Useful on code paths that may never read the resource, especially in
+/// `no_std` environments where allocation should wait until it is certain.
+/// Both states drop normally: a pending resource has allocated nothing, and a
+/// ready one frees its buffer like any `RawResource`.
+#[derive(Clone, Debug)]
+pub enum LazyResource {
+    /// Not yet initialized; the function produces the bytes.
+    Pending(fn() -> Vec<u8>),
+    /// Initialized.
+    Ready(RawResource),
+}
+
+impl LazyResource {
+    /// Creates a resource that calls `init` on first access.
+    pub const fn new(init: fn() -> Vec<u8>) -> Self {
+        Self::Pending(init)
+    }
+
+    /// Returns the resource, running the initializer if this is the first
+    /// call.
+    pub fn get(&mut self) -> &RawResource {
+        if let Self::Pending(init) = *self {
+            let resource = RawResource::refine(init()).expect("refine is infallible");
+            *self = Self::Ready(resource);
+        }
+        match self {
+            Self::Ready(resource) => resource,
+            Self::Pending(_) => unreachable!("initialized above"),
+        }
+    }
+
+    /// Returns `true` if the initializer has already run.
+    #[inline]
+    pub fn is_ready(&self) -> bool {
+        matches!(self, Self::Ready(_))
+    }
+
+    /// Consumes the wrapper, initializing it if necessary.
+    pub fn into_inner(mut self) -> RawResource {
+        self.get();
+        match self {
+            Self::Ready(resource) => resource,
+            Self::Pending(_) => unreachable!("initialized above"),
+        }
+    }
+}
+
+impl From<RawResource> for LazyResource {
+    fn from(resource: RawResource) -> Self {
+        Self::Ready(resource)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use core::sync::atomic::{AtomicUsize, Ordering};
+
+    static CALLS: AtomicUsize = AtomicUsize::new(0);
+
+    fn init() -> Vec<u8> {
+        CALLS.fetch_add(1, Ordering::Relaxed);
+        alloc::vec![1, 2, 3]
+    }
+
+    #[test]
+    fn test_lazy_initializes_once() {
+        let mut lazy = LazyResource::new(init);
+        assert!(!lazy.is_ready());
+        assert_eq!(CALLS.load(Ordering::Relaxed), 0);
+
+        assert_eq!(lazy.get().as_slice(), &[1, 2, 3]);
+        assert!(lazy.is_ready());
+        lazy.get();
+        assert_eq!(CALLS.load(Ordering::Relaxed), 1);
+
+        assert_eq!(lazy.into_inner().as_slice(), &[1, 2, 3]);
+    }
+
+    #[test]
+    fn test_lazy_drop_in_both_states() {
+        drop(LazyResource::new(Vec::new));
+        drop(LazyResource::from(RawResource::from("ready")));
+    }
+}
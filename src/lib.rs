@@ -3,6 +3,22 @@
 //! Provides `RawResource` for managing raw byte buffers without allocation overhead.
 //! Uses `ManuallyDrop` to take ownership of data while exposing raw pointers.
 //!
+//! # Features
+//!
+//! - `std`: implements `std::io::{Read, Seek}` for [`ResourceCursor`] and
+//!   `std::io::Write` for [`ResourceWriter`].
+//! - `embedded-io`: implements `embedded_io::Read` for [`ResourceCursor`].
+//! - `arbitrary`: implements `arbitrary::Arbitrary` for [`RawResource`].
+//! - `base64`: adds `RawResource::refine_from_base64` and
+//!   `RawResource::as_base64` via the `base64` crate.
+//! - `crc`: adds `RawResource::checksum_crc32` via the `crc` crate.
+//! - `memchr`: accelerates byte and substring search with the `memchr` crate.
+//! - `proptest`: adds `arb_resource` and `arb_resource_of_len` strategies.
+//! - `serde`: implements `Serialize`/`Deserialize` for [`RawResource`], as a
+//!   hex string in human-readable formats and raw bytes otherwise.
+//! - `subtle`: makes `RawResource::constant_time_eq` delegate to the `subtle`
+//!   crate.
+//!
 //! # Safety
 //!
 //! This module uses unsafe code to manage memory manually. The `RawResource` struct
@@ -11,27 +27,212 @@
 //!
 //! Caller is responsible for ensuring the buffer outlives all references to it.
 
-#![no_std]
+#![cfg_attr(not(feature = "std"), no_std)]
 
 extern crate alloc;
 
+mod aligned;
+mod annotated;
+#[cfg(feature = "arbitrary")]
+mod arbitrary;
+mod arc;
+#[cfg(feature = "base64")]
+mod base64;
+mod bits;
+mod builder;
+mod chain;
+mod checksum;
+mod codec;
+mod constant_time;
+mod cursor;
+mod diff;
+mod display;
+mod frame;
+mod hasher;
+mod hex;
+mod id;
+mod iter;
+mod lazy;
+mod merge;
+mod mutable;
+mod pinned;
+mod pool;
+mod queue;
+mod ring;
+mod rle;
+mod search;
+#[cfg(feature = "serde")]
+mod serde;
+#[cfg(target_has_atomic = "64")]
+mod slab;
+mod snapshot;
+mod stack;
+mod stats;
+#[cfg(feature = "proptest")]
+mod strategy;
+mod text;
+mod transform;
+mod validate;
+mod view;
+mod volatile;
+mod writer;
+
+#[cfg(feature = "base64")]
+pub use self::base64::{Base64Error, Base64Variant};
+pub use annotated::ResourceAnnotated;
+pub use arc::ArcResource;
+pub use builder::ResourceBuilder;
+pub use chain::{ResourceChain, ResourceSet};
+pub use codec::{IdentityCodec, ResourceDecoder, ResourceEncoder};
+pub use cursor::ResourceCursor;
+pub use diff::ResourceDiff;
+pub use frame::FramePrefixLen;
+pub use hasher::{ResourceHasher, hash_resource};
+pub use hex::HexError;
+pub use id::{IdentifiedResource, ResourceId};
+pub use iter::IntoIter;
+pub use lazy::LazyResource;
+pub use merge::{concat, merge};
+pub use mutable::MutRawResource;
+pub use pinned::PinnedResource;
+pub use pool::{RefineGuard, ResourcePool};
+pub use queue::ResourceQueue;
+pub use ring::ResourceRingBuffer;
+pub use rle::{RleDecoder, RleEncoder};
+#[cfg(target_has_atomic = "64")]
+pub use slab::{ResourceSlabAllocator, ResourceSlot};
+pub use snapshot::ResourceSnapshot;
+pub use stack::ResourceStack;
+pub use stats::MemoryStats;
+#[cfg(feature = "proptest")]
+pub use strategy::{arb_resource, arb_resource_of_len};
+pub use transform::{
+    FilterTransform, NotTransform, ResourceTransform, XorTransform, apply_transform,
+};
+pub use validate::{MagicBytesValidator, NonEmptyValidator, ResourceValidator, Utf8Validator};
+pub use view::ResourceView;
+pub use volatile::VolatileResource;
+pub use writer::ResourceWriter;
+
 use alloc::vec::Vec;
-use bytes::Bytes;
+use bytes::{Bytes, BytesMut};
+use core::borrow::Borrow;
+use core::cmp::Ordering;
+use core::hash::{Hash, Hasher};
+use core::ops::{Deref, Index, Range};
+use core::slice::SliceIndex;
 
-/// Error returned by logistics operations.
+/// Error returned by refinement and other logistics operations.
+///
+/// Implements `core::error::Error`, so it composes with `thiserror`/`anyhow`
+/// without requiring `std`.
 #[derive(thiserror::Error, Debug, Clone, PartialEq, Eq)]
-pub enum LogisticsError {
-    // EmptyBuffer removed as empty buffers are now allowed
+#[non_exhaustive]
+pub enum RefinementError {
+    /// An operation required a non-empty input but received an empty one.
+    ///
+    /// Empty resources themselves are valid "zero-byte resources"; this is
+    /// only returned where an empty argument makes the operation meaningless.
+    #[error("buffer is empty")]
+    EmptyBuffer,
+    /// A computed length or capacity does not fit in `usize`.
+    #[error("capacity overflow")]
+    CapacityOverflow,
+    /// An index or offset lies beyond the end of the buffer.
+    #[error("index {index} out of bounds for length {len}")]
+    OutOfBounds {
+        /// The offending index.
+        index: usize,
+        /// Length of the buffer that was indexed.
+        len: usize,
+    },
+    /// Two buffers that must have equal lengths did not.
+    #[error("length mismatch: expected {expected} bytes, got {actual}")]
+    LengthMismatch {
+        /// Length required by the operation.
+        expected: usize,
+        /// Length that was supplied.
+        actual: usize,
+    },
+    /// A requested alignment is not a power of two.
+    #[error("alignment {align} is not a power of two")]
+    InvalidAlignment {
+        /// The rejected alignment.
+        align: usize,
+    },
+    /// Raw parts passed to a reclaiming constructor are inconsistent.
+    #[error("invalid raw parts: null pointer or length exceeds capacity")]
+    InvalidRawParts,
+    /// A read or write needed more bytes than remain in the buffer.
+    #[error("needed {needed} bytes but only {remaining} remain")]
+    Exhausted {
+        /// Number of bytes the operation required.
+        needed: usize,
+        /// Number of bytes that were left.
+        remaining: usize,
+    },
+    /// Bytes that were required to be UTF-8 are not.
+    #[error("invalid UTF-8 after {valid_up_to} valid bytes")]
+    InvalidUtf8 {
+        /// Length of the longest valid UTF-8 prefix.
+        valid_up_to: usize,
+    },
+    /// The buffer does not begin with the expected magic header.
+    #[error("missing expected magic header")]
+    BadMagic,
+    /// Encoded input is truncated or contains an invalid sequence.
+    #[error("malformed encoding at offset {offset}")]
+    MalformedEncoding {
+        /// Offset of the first byte of the invalid sequence.
+        offset: usize,
+    },
 }
 
+/// Former name of [`RefinementError`].
+#[deprecated(note = "Use RefinementError instead")]
+pub type LogisticsError = RefinementError;
+
 /// A zero-copy buffer resource representing "Hilirisasi Data" (Downstreaming Data).
 ///
 /// This struct wraps `bytes::Bytes` to provide efficient, reference-counted
-/// access to contiguous memory without unnecessary copying.
+/// access to contiguous memory without unnecessary copying. For in-place
+/// modification, convert it into a [`MutRawResource`] with
+/// [`into_mut`](Self::into_mut).
+///
+/// # Cloning
+///
+/// `Clone` is cheap: it bumps a reference count instead of copying. Clones are
+/// still independent values: [`into_mut`](Self::into_mut) copies the bytes
+/// first when the buffer is shared, and every clone can be dropped on its own.
+/// Use [`deep_clone`](Self::deep_clone) when a fresh, unshared allocation is
+/// needed up front.
+///
+/// # Comparison
+///
+/// Equality, ordering, and hashing delegate to the byte contents, so resources
+/// behave like `[u8]` in maps and sorted collections. The
+/// [`generation`](Self::generation) counter is ignored:
+///
+/// ```
+/// use praborrow_logistics::RawResource;
+/// use std::collections::BTreeMap;
+///
+/// let a = RawResource::refine(vec![1, 2, 3]).unwrap();
+/// let b = RawResource::refine(vec![1, 2, 3]).unwrap();
+/// assert_eq!(a, b);
+///
+/// let mut counts: BTreeMap<RawResource, usize> = BTreeMap::new();
+/// *counts.entry(a).or_default() += 1;
+/// *counts.entry(b).or_default() += 1;
+/// *counts.entry(RawResource::refine(vec![0]).unwrap()).or_default() += 1;
+/// assert_eq!(counts.len(), 2);
+/// assert_eq!(counts.keys().next().unwrap().as_slice(), &[0]);
+/// ```
 #[doc(alias = "PinnedBuffer")]
-#[derive(Clone, Debug, PartialEq, Eq)]
+#[derive(Clone)]
 pub struct RawResource {
     inner: Bytes,
+    generation: u64,
 }
 
 // Bytes is Send + Sync
@@ -45,12 +246,361 @@ impl RawResource {
     ///
     /// # Errors
     ///
-    /// Returns an error if the input data is empty.
-    pub fn refine(data: Vec<u8>) -> Result<Self, LogisticsError> {
+    /// Currently infallible: empty buffers are valid zero-byte resources. The
+    /// `Result` is kept so validation can be added without breaking callers.
+    pub fn refine(data: Vec<u8>) -> Result<Self, RefinementError> {
         // Empty buffers are now valid "zero-byte resources"
-        Ok(Self {
-            inner: Bytes::from(data),
-        })
+        Ok(Self::from_bytes(Bytes::from(data)))
+    }
+
+    /// Refines `data`, first reserving room for at least `min_cap` bytes.
+    ///
+    /// The spare capacity is kept with the allocation, so later growth such as
+    /// [`resize`](Self::resize) can happen in place while the resource is
+    /// uniquely owned.
+    ///
+    /// `Bytes` does not report its allocation size, so capacity is observed on
+    /// the mutable side, e.g. `resource.into_mut().capacity()`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`RefinementError::CapacityOverflow`] if the reservation fails.
+    pub fn refine_with_capacity(
+        mut data: Vec<u8>,
+        min_cap: usize,
+    ) -> Result<Self, RefinementError> {
+        data.try_reserve(min_cap.saturating_sub(data.len()))
+            .map_err(|_| RefinementError::CapacityOverflow)?;
+        Self::refine(data)
+    }
+
+    /// Allocates a resource of exactly `cap` zero bytes.
+    ///
+    /// Unlike `Vec::with_capacity`, the length equals `cap`: every byte is
+    /// initialized and readable, ready to be overwritten in place through
+    /// [`into_mut`](Self::into_mut) and a [`ResourceWriter`].
+    ///
+    /// # Errors
+    ///
+    /// Returns [`RefinementError::CapacityOverflow`] if `cap` exceeds
+    /// `isize::MAX`.
+    pub fn with_capacity(cap: usize) -> Result<Self, RefinementError> {
+        if cap > isize::MAX as usize {
+            return Err(RefinementError::CapacityOverflow);
+        }
+        Ok(Self::from_bytes(BytesMut::zeroed(cap).freeze()))
+    }
+
+    /// Allocates a resource of `len` zero bytes, ready for FFI or DMA writes.
+    ///
+    /// Uses a zeroing allocation rather than writing zeros after the fact, and
+    /// frees the caller from juggling a temporary `Vec`. Same as
+    /// [`with_capacity`](Self::with_capacity), named for the refine family.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`RefinementError::CapacityOverflow`] if `len` exceeds
+    /// `isize::MAX`.
+    pub fn refine_zeroed(len: usize) -> Result<Self, RefinementError> {
+        Self::with_capacity(len)
+    }
+
+    /// Allocates a resource of `len` bytes without initializing them.
+    ///
+    /// Skips the zeroing pass of [`refine_zeroed`](Self::refine_zeroed) for
+    /// buffers that are about to be fully overwritten, such as DMA targets.
+    /// Fill it through [`into_mut`](Self::into_mut) and
+    /// [`MutRawResource::as_mut_ptr`], which is zero-copy for a fresh resource.
+    ///
+    /// # Safety
+    ///
+    /// Every byte must be written before any byte is read. This includes
+    /// implicit reads: `as_slice`, `Deref`, comparisons, `Debug`, and cloning
+    /// followed by `into_mut` (which copies) all read the bytes. Writes must go
+    /// through raw pointers, since a `&mut [u8]` to uninitialized memory is
+    /// not allowed to be read through either.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`RefinementError::CapacityOverflow`] if the allocation fails.
+    pub unsafe fn refine_uninitialized(len: usize) -> Result<Self, RefinementError> {
+        let mut data = Vec::new();
+        data.try_reserve_exact(len)
+            .map_err(|_| RefinementError::CapacityOverflow)?;
+        // SAFETY: capacity is at least `len`; the caller promises to write
+        // every byte before reading any.
+        unsafe { data.set_len(len) };
+        Self::refine(data)
+    }
+
+    /// Wraps a `'static` byte slice without copying or allocating.
+    ///
+    /// The resource only borrows `data`; dropping it never deallocates. This is
+    /// sound because `'static` data outlives every resource, and the buffer is
+    /// never written through: [`into_mut`](Self::into_mut) and
+    /// [`into_vec`](Self::into_vec) copy the bytes out first.
+    ///
+    /// # Errors
+    ///
+    /// Currently infallible, mirroring [`refine`](Self::refine).
+    pub fn from_static(data: &'static [u8]) -> Result<Self, RefinementError> {
+        Ok(Self::from_bytes(Bytes::from_static(data)))
+    }
+
+    pub(crate) fn from_bytes(inner: Bytes) -> Self {
+        Self {
+            inner,
+            generation: 0,
+        }
+    }
+
+    pub(crate) fn into_bytes(self) -> Bytes {
+        self.inner
+    }
+
+    /// Returns a copy of this resource backed by a new heap allocation.
+    ///
+    /// Unlike `clone`, this always allocates and copies `len()` bytes.
+    pub fn deep_clone(&self) -> Self {
+        Self::from_bytes(Bytes::copy_from_slice(&self.inner))
+    }
+
+    /// Consumes the resource and returns the underlying bytes as a `Vec<u8>`.
+    ///
+    /// Zero-copy when this is the only handle to a buffer that originally came
+    /// from a `Vec`; otherwise the bytes are copied into a new `Vec`.
+    pub fn into_vec(self) -> Vec<u8> {
+        self.inner.into()
+    }
+
+    /// Borrows the whole resource as a [`ResourceView`].
+    ///
+    /// Zero-copy, and equivalent to `ResourceView::from(&resource)`; useful
+    /// where parsing code works on views and should not take ownership.
+    #[inline]
+    pub fn borrow_view(&self) -> ResourceView<'_> {
+        ResourceView::new(self.as_slice())
+    }
+
+    /// Divides the resource into two zero-copy views at `mid`.
+    ///
+    /// The first view contains `[0, mid)` and the second `[mid, len)`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `mid > len`, matching `<[u8]>::split_at`.
+    pub fn split_at(&self, mid: usize) -> (ResourceView<'_>, ResourceView<'_>) {
+        let (head, tail) = self.as_slice().split_at(mid);
+        (ResourceView::new(head), ResourceView::new(tail))
+    }
+
+    /// Iterates over non-overlapping zero-copy views of `chunk_size` bytes.
+    ///
+    /// Matches `<[u8]>::chunks`: the last view may be shorter. Views borrow
+    /// from the resource and cannot outlive it:
+    ///
+    /// ```compile_fail
+    /// use praborrow_logistics::RawResource;
+    ///
+    /// let resource = RawResource::refine(vec![1, 2, 3]).unwrap();
+    /// let first = resource.chunks(2).next().unwrap();
+    /// drop(resource);
+    /// assert_eq!(first.len(), 2);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if `chunk_size` is 0.
+    pub fn chunks(
+        &self,
+        chunk_size: usize,
+    ) -> impl ExactSizeIterator<Item = ResourceView<'_>> + DoubleEndedIterator {
+        self.as_slice().chunks(chunk_size).map(ResourceView::new)
+    }
+
+    /// Iterates over overlapping zero-copy views of `size` bytes.
+    ///
+    /// Matches `<[u8]>::windows`: yields nothing if `size > len`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `size` is 0.
+    pub fn windows(
+        &self,
+        size: usize,
+    ) -> impl ExactSizeIterator<Item = ResourceView<'_>> + DoubleEndedIterator {
+        self.as_slice().windows(size).map(ResourceView::new)
+    }
+
+    /// Iterates over the zero-copy segments separated by `delimiter`.
+    ///
+    /// Matches `<[u8]>::split`: delimiters are excluded, and empty segments
+    /// are yielded between consecutive delimiters and after a trailing one.
+    pub fn split_on_byte(
+        &self,
+        delimiter: u8,
+    ) -> impl DoubleEndedIterator<Item = ResourceView<'_>> {
+        self.as_slice()
+            .split(move |&b| b == delimiter)
+            .map(ResourceView::new)
+    }
+
+    /// Consumes the resource and splits it into two owned halves at `mid`.
+    ///
+    /// Zero-copy: both halves keep sharing the original allocation, which is
+    /// freed once both are dropped. Either half may be empty.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`RefinementError::OutOfBounds`] if `mid > len`.
+    pub fn split_off(self, mid: usize) -> Result<(RawResource, RawResource), RefinementError> {
+        let len = self.len();
+        if mid > len {
+            return Err(RefinementError::OutOfBounds { index: mid, len });
+        }
+        let mut head = self.inner;
+        let tail = head.split_off(mid);
+        Ok((Self::from_bytes(head), Self::from_bytes(tail)))
+    }
+
+    /// Returns the byte at `index`, or `None` if it is out of bounds.
+    #[inline]
+    pub fn get(&self, index: usize) -> Option<u8> {
+        self.as_slice().get(index).copied()
+    }
+
+    /// Returns the bytes in `range`, or `None` if it is out of bounds or
+    /// `range.start > range.end`.
+    #[inline]
+    pub fn get_slice(&self, range: Range<usize>) -> Option<&[u8]> {
+        self.as_slice().get(range)
+    }
+
+    /// Returns a zero-copy view of the bytes in `[start, end)`.
+    ///
+    /// The non-panicking counterpart of `&resource[start..end]`, which works
+    /// through `Deref` and panics on a bad range.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`RefinementError::OutOfBounds`] with `index: end` if
+    /// `end > len`, or with `index: start, len: end` if `start > end`.
+    pub fn subslice(&self, start: usize, end: usize) -> Result<ResourceView<'_>, RefinementError> {
+        let len = self.len();
+        if end > len {
+            return Err(RefinementError::OutOfBounds { index: end, len });
+        }
+        if start > end {
+            return Err(RefinementError::OutOfBounds {
+                index: start,
+                len: end,
+            });
+        }
+        Ok(ResourceView::new(&self.as_slice()[start..end]))
+    }
+
+    /// Runs `f` against a uniquely owned view of the buffer, copy-on-write.
+    ///
+    /// Zero-copy when this is the only handle; otherwise the bytes are copied
+    /// first so other clones are unaffected. Advances the generation.
+    fn modify<R>(&mut self, f: impl FnOnce(&mut BytesMut) -> R) -> R {
+        let mut buf = BytesMut::from(core::mem::take(&mut self.inner));
+        let result = f(&mut buf);
+        self.inner = buf.freeze();
+        self.bump_generation();
+        result
+    }
+
+    fn bump_generation(&mut self) {
+        self.generation = self.generation.wrapping_add(1);
+    }
+
+    /// Returns a counter that advances on every in-place mutation.
+    ///
+    /// Methods that change the bytes or length through `&mut self` (such as
+    /// [`fill`](Self::fill), [`xor_mask_inplace`](Self::xor_mask_inplace),
+    /// [`resize`](Self::resize), [`truncate`](Self::truncate) and
+    /// [`prepend`](Self::prepend)) increment it, as do writes made through
+    /// [`into_mut`](Self::into_mut) before [`MutRawResource::freeze`]; clones
+    /// start with the original's value and then count independently. Capture
+    /// it before handing the resource to a pipeline stage to detect whether
+    /// that stage modified it. Newly constructed resources start at 0.
+    #[inline]
+    pub fn generation(&self) -> u64 {
+        self.generation
+    }
+
+    /// Gives up ownership of the buffer, returning `(ptr, len, cap)`.
+    ///
+    /// The allocation is not freed; reclaim it later with
+    /// [`from_leaked_ptr`](Self::from_leaked_ptr) or it leaks. The buffer is
+    /// converted to a `Vec<u8>` first, which copies if it is shared or static.
+    ///
+    /// ```
+    /// use praborrow_logistics::RawResource;
+    ///
+    /// // Stand-in for a C library that holds the buffer and hands it back
+    /// // later through a completion callback.
+    /// struct CLibrary { held: Option<(*mut u8, usize, usize)> }
+    ///
+    /// impl CLibrary {
+    ///     extern "C" fn submit(&mut self, ptr: *mut u8, len: usize, cap: usize) {
+    ///         self.held = Some((ptr, len, cap));
+    ///     }
+    ///     extern "C" fn complete(&mut self) -> (*mut u8, usize, usize) {
+    ///         self.held.take().unwrap()
+    ///     }
+    /// }
+    ///
+    /// let resource = RawResource::refine(vec![1, 2, 3]).unwrap();
+    /// let mut lib = CLibrary { held: None };
+    /// let (ptr, len, cap) = resource.leak();
+    /// lib.submit(ptr, len, cap);
+    ///
+    /// let (ptr, len, cap) = lib.complete();
+    /// // SAFETY: the parts came from `leak` and the library no longer uses them.
+    /// let reclaimed = unsafe { RawResource::from_leaked_ptr(ptr, len, cap) }.unwrap();
+    /// assert_eq!(reclaimed.as_slice(), &[1, 2, 3]);
+    /// ```
+    pub fn leak(self) -> (*mut u8, usize, usize) {
+        let mut vec = core::mem::ManuallyDrop::new(self.into_vec());
+        (vec.as_mut_ptr(), vec.len(), vec.capacity())
+    }
+
+    /// Reclaims ownership of a buffer previously released with
+    /// [`leak`](Self::leak).
+    ///
+    /// # Safety
+    ///
+    /// `ptr`, `len` and `cap` must be exactly the values returned by one call
+    /// to `leak`, the buffer must not be reclaimed more than once, and nothing
+    /// else may access it after this call.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`RefinementError::InvalidRawParts`] if `ptr` is null or
+    /// `len > cap`; these checks catch obvious misuse but cannot prove the
+    /// safety contract.
+    pub unsafe fn from_leaked_ptr(
+        ptr: *mut u8,
+        len: usize,
+        cap: usize,
+    ) -> Result<Self, RefinementError> {
+        if ptr.is_null() || len > cap {
+            return Err(RefinementError::InvalidRawParts);
+        }
+        // SAFETY: the caller guarantees the parts describe a `Vec<u8>`
+        // allocation released by `leak` and now exclusively ours.
+        let vec = unsafe { Vec::from_raw_parts(ptr, len, cap) };
+        Self::refine(vec)
+    }
+
+    /// Converts into a uniquely owned [`MutRawResource`] for in-place writes.
+    ///
+    /// Zero-copy when this is the only handle to the buffer; otherwise the
+    /// bytes are copied so other clones never observe the writes.
+    pub fn into_mut(self) -> MutRawResource {
+        MutRawResource::from_bytes_mut(self.inner.into(), self.generation)
     }
 
     /// Returns the raw pointer to the resource data.
@@ -73,51 +623,1298 @@ impl RawResource {
 
     /// Returns a byte slice of the resource.
     ///
-    /// # Safety
-    ///
-    /// This method is safe. The `unsafe` keyword is kept for backward compatibility
-    /// but the implementation delegates to safe `Bytes::as_ref()`.
-    ///
-    /// # Safe Usage
-    /// The returned slice is tied to the lifetime of `self`.
-    #[deprecated(note = "Use as_bytes() instead")]
-    pub unsafe fn as_slice(&self) -> &[u8] {
+    /// The returned slice borrows from `self`, so the borrow checker guarantees
+    /// it cannot outlive the resource. No `unsafe` block is required at the
+    /// call site.
+    #[inline]
+    pub fn as_slice(&self) -> &[u8] {
         &self.inner
     }
 
-    /// Returns a safe slice.
+    /// Returns a byte slice of the resource.
+    ///
+    /// Alias of [`as_slice`](Self::as_slice).
+    #[inline]
     pub fn as_bytes(&self) -> &[u8] {
-        &self.inner
+        self.as_slice()
     }
-}
 
-#[cfg(test)]
-mod tests {
-    use super::*;
+    /// Shortens the resource to `new_len` bytes, keeping the allocation.
+    ///
+    /// Zero-copy: only the visible length changes. Truncating to `0` yields a
+    /// valid empty resource.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`RefinementError::OutOfBounds`] if `new_len > len`.
+    pub fn truncate(&mut self, new_len: usize) -> Result<(), RefinementError> {
+        let len = self.len();
+        if new_len > len {
+            return Err(RefinementError::OutOfBounds {
+                index: new_len,
+                len,
+            });
+        }
+        self.inner.truncate(new_len);
+        self.bump_generation();
+        Ok(())
+    }
 
-    #[test]
-    fn test_refine_success() {
-        let data = alloc::vec![1, 2, 3, 4, 5];
-        let resource = RawResource::refine(data).expect("should succeed");
-        assert_eq!(resource.len(), 5);
-        assert!(!resource.is_empty());
+    /// Releases spare capacity, e.g. after [`truncate`](Self::truncate).
+    ///
+    /// When this is the only handle and the allocation is larger than
+    /// `len()`, the bytes are moved into an exactly sized allocation. Shared
+    /// and static buffers are left as they are, since other handles keep the
+    /// original allocation alive anyway.
+    pub fn shrink_to_fit(&mut self) {
+        let inner = core::mem::take(&mut self.inner);
+        self.inner = match inner.try_into_mut() {
+            Ok(buf) if buf.capacity() > buf.len() => Bytes::copy_from_slice(&buf),
+            Ok(buf) => buf.freeze(),
+            Err(shared) => shared,
+        };
     }
 
-    #[test]
-    fn test_refine_empty_success() {
-        let data: Vec<u8> = alloc::vec![];
-        let resource = RawResource::refine(data).expect("should succeed now");
-        assert!(resource.is_empty());
-        assert_eq!(resource.len(), 0);
+    /// Appends `extra` to the end of the resource.
+    ///
+    /// Grows in place when this is the only handle and there is spare
+    /// capacity; otherwise the bytes move to a new allocation, invalidating
+    /// any pointer previously obtained from [`as_ptr`](Self::as_ptr). A shared
+    /// buffer is copied first so other clones are unaffected.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`RefinementError::EmptyBuffer`] if `extra` is empty, or
+    /// [`RefinementError::CapacityOverflow`] if the new length overflows.
+    pub fn try_extend(&mut self, extra: &[u8]) -> Result<(), RefinementError> {
+        if extra.is_empty() {
+            return Err(RefinementError::EmptyBuffer);
+        }
+        self.len()
+            .checked_add(extra.len())
+            .filter(|&total| total <= isize::MAX as usize)
+            .ok_or(RefinementError::CapacityOverflow)?;
+        self.modify(|buf| buf.extend_from_slice(extra));
+        Ok(())
     }
 
-    #[test]
-    #[allow(deprecated)]
-    fn test_as_slice() {
-        let data = alloc::vec![10, 20, 30];
-        let resource = RawResource::refine(data).expect("should succeed");
-        let slice = unsafe { resource.as_slice() };
-        assert_eq!(slice, &[10, 20, 30]);
+    /// Appends the bytes of `other` to `self`, consuming `other`.
+    ///
+    /// Unlike [`merge`], no third buffer is allocated: when
+    /// this is the only handle, `self`'s allocation is grown in place,
+    /// reusing any spare capacity. A shared buffer is copied first so other
+    /// clones are unaffected. Merging an empty `other` is a no-op.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`RefinementError::CapacityOverflow`] if the combined length
+    /// overflows; `self` is left unchanged.
+    pub fn try_merge_in_place(&mut self, other: RawResource) -> Result<(), RefinementError> {
+        if other.is_empty() {
+            return Ok(());
+        }
+        self.len()
+            .checked_add(other.len())
+            .filter(|&total| total <= isize::MAX as usize)
+            .ok_or(RefinementError::CapacityOverflow)?;
+        self.modify(|buf| buf.extend_from_slice(other.as_slice()));
+        Ok(())
+    }
+
+    /// Inserts `prefix` before the existing bytes, e.g. to add a header.
+    ///
+    /// O(n): always allocates a new buffer of `prefix.len() + len()` bytes
+    /// and copies both parts into it, so any pointer previously obtained from
+    /// [`as_ptr`](Self::as_ptr) is invalidated. Other clones keep the original
+    /// bytes. An empty `prefix` is a no-op.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`RefinementError::CapacityOverflow`] if the new length
+    /// overflows.
+    pub fn prepend(&mut self, prefix: &[u8]) -> Result<(), RefinementError> {
+        if prefix.is_empty() {
+            return Ok(());
+        }
+        let total = prefix
+            .len()
+            .checked_add(self.len())
+            .filter(|&total| total <= isize::MAX as usize)
+            .ok_or(RefinementError::CapacityOverflow)?;
+        let mut joined = Vec::with_capacity(total);
+        joined.extend_from_slice(prefix);
+        joined.extend_from_slice(self.as_slice());
+        self.inner = Bytes::from(joined);
+        self.bump_generation();
+        Ok(())
+    }
+
+    /// Resizes the resource to `new_len` bytes, padding with `fill` if it grows.
+    ///
+    /// Copies the bytes first if the buffer is shared; growing may reallocate.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`RefinementError::CapacityOverflow`] if `new_len` exceeds
+    /// `isize::MAX`.
+    pub fn resize(&mut self, new_len: usize, fill: u8) -> Result<(), RefinementError> {
+        if new_len > isize::MAX as usize {
+            return Err(RefinementError::CapacityOverflow);
+        }
+        self.modify(|buf| buf.resize(new_len, fill));
+        Ok(())
+    }
+
+    /// Overwrites every byte with `byte`.
+    ///
+    /// Copies the bytes first if the buffer is shared, so other clones keep
+    /// their contents.
+    pub fn fill(&mut self, byte: u8) {
+        self.modify(|buf| buf.fill(byte));
+    }
+
+    /// Overwrites every byte with zero in a way the compiler will not elide.
+    ///
+    /// Uses volatile writes followed by a compiler fence; a plain
+    /// `ptr::write_bytes` or loop is a dead store the optimizer may remove.
+    /// If the buffer is shared with other clones, only this handle's private
+    /// copy is zeroed and the shared bytes are left untouched.
+    pub fn zeroize(&mut self) {
+        self.modify(|buf| {
+            for byte in buf.iter_mut() {
+                // SAFETY: `byte` is a valid, aligned, exclusive reference.
+                unsafe { core::ptr::write_volatile(byte, 0) };
+            }
+        });
+        core::sync::atomic::compiler_fence(core::sync::atomic::Ordering::SeqCst);
+    }
+
+    /// Overwrites the whole resource with the contents of `src`.
+    ///
+    /// Copies the bytes first if the buffer is shared.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`RefinementError::LengthMismatch`] if `src.len() != len`.
+    pub fn copy_from_slice(&mut self, src: &[u8]) -> Result<(), RefinementError> {
+        if src.len() != self.len() {
+            return Err(RefinementError::LengthMismatch {
+                expected: self.len(),
+                actual: src.len(),
+            });
+        }
+        self.modify(|buf| buf.copy_from_slice(src));
+        Ok(())
+    }
+
+    /// Copies the whole resource into `dst`, e.g. an FFI output buffer.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`RefinementError::LengthMismatch`] if `dst.len() != len`.
+    pub fn copy_to_slice(&self, dst: &mut [u8]) -> Result<(), RefinementError> {
+        if dst.len() != self.len() {
+            return Err(RefinementError::LengthMismatch {
+                expected: self.len(),
+                actual: dst.len(),
+            });
+        }
+        dst.copy_from_slice(self.as_slice());
+        Ok(())
+    }
+
+    /// Copies the whole resource into `dst` starting at `offset`, leaving
+    /// the rest of `dst` untouched.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`RefinementError::OutOfBounds`] if `offset + len` exceeds
+    /// `dst.len()`.
+    pub fn copy_to_offset(&self, dst: &mut [u8], offset: usize) -> Result<(), RefinementError> {
+        let end = offset
+            .checked_add(self.len())
+            .filter(|&end| end <= dst.len())
+            .ok_or(RefinementError::OutOfBounds {
+                index: offset.saturating_add(self.len()),
+                len: dst.len(),
+            })?;
+        dst[offset..end].copy_from_slice(self.as_slice());
+        Ok(())
+    }
+
+    /// Returns the offset of the first occurrence of `needle`, if any.
+    #[inline]
+    pub fn find_byte(&self, needle: u8) -> Option<usize> {
+        search::find_byte(self.as_slice(), needle)
+    }
+
+    /// Returns the offset of the first occurrence of `needle`, if any.
+    ///
+    /// An empty `needle` matches at offset 0.
+    #[inline]
+    pub fn find_slice(&self, needle: &[u8]) -> Option<usize> {
+        search::find_slice(self.as_slice(), needle)
+    }
+
+    /// Returns the offset of the last occurrence of `needle`, if any.
+    #[inline]
+    pub fn rfind_byte(&self, needle: u8) -> Option<usize> {
+        search::rfind_byte(self.as_slice(), needle)
+    }
+
+    /// Returns the offset of the last occurrence of `needle`, if any.
+    ///
+    /// An empty `needle` matches at offset `len`.
+    #[inline]
+    pub fn rfind_slice(&self, needle: &[u8]) -> Option<usize> {
+        search::rfind_slice(self.as_slice(), needle)
+    }
+
+    /// Returns the number of bytes equal to `needle`.
+    #[inline]
+    pub fn count_byte(&self, needle: u8) -> usize {
+        search::count_byte(self.as_slice(), needle)
+    }
+
+    /// Returns the number of non-overlapping occurrences of `needle`.
+    ///
+    /// Matches are counted left to right, so `"aaa"` contains `"aa"` once.
+    /// An empty `needle` matches `len() + 1` times.
+    #[inline]
+    pub fn count_slice(&self, needle: &[u8]) -> usize {
+        search::count_slice(self.as_slice(), needle)
+    }
+
+    /// Returns `true` if `needle` occurs anywhere in the resource.
+    #[inline]
+    pub fn contains_slice(&self, needle: &[u8]) -> bool {
+        self.find_slice(needle).is_some()
+    }
+
+    /// Returns `true` if the resource begins with `prefix`.
+    #[inline]
+    pub fn starts_with(&self, prefix: &[u8]) -> bool {
+        self.as_slice().starts_with(prefix)
+    }
+
+    /// Returns `true` if the resource ends with `suffix`.
+    #[inline]
+    pub fn ends_with(&self, suffix: &[u8]) -> bool {
+        self.as_slice().ends_with(suffix)
+    }
+
+    /// Returns a view with leading and trailing bytes matching `f` removed.
+    pub fn trim_bytes(&self, f: impl Fn(u8) -> bool) -> ResourceView<'_> {
+        let bytes = self.as_slice();
+        let start = bytes.iter().position(|&b| !f(b)).unwrap_or(bytes.len());
+        let end = bytes.iter().rposition(|&b| !f(b)).map_or(start, |i| i + 1);
+        ResourceView::new(&bytes[start..end])
+    }
+
+    /// Returns a view with leading bytes matching `f` removed.
+    pub fn trim_leading(&self, f: impl Fn(u8) -> bool) -> ResourceView<'_> {
+        let bytes = self.as_slice();
+        let start = bytes.iter().position(|&b| !f(b)).unwrap_or(bytes.len());
+        ResourceView::new(&bytes[start..])
+    }
+
+    /// Returns a view with trailing bytes matching `f` removed.
+    pub fn trim_trailing(&self, f: impl Fn(u8) -> bool) -> ResourceView<'_> {
+        let bytes = self.as_slice();
+        let end = bytes.iter().rposition(|&b| !f(b)).map_or(0, |i| i + 1);
+        ResourceView::new(&bytes[..end])
+    }
+
+    /// XORs every byte with `key`, repeating `key` as needed.
+    ///
+    /// Applying the same mask twice restores the original bytes. Copies the
+    /// bytes first if the buffer is shared.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`RefinementError::EmptyBuffer`] if `key` is empty.
+    pub fn xor_mask_inplace(&mut self, key: &[u8]) -> Result<(), RefinementError> {
+        if key.is_empty() {
+            return Err(RefinementError::EmptyBuffer);
+        }
+        self.modify(|buf| {
+            for (byte, k) in buf.iter_mut().zip(key.iter().cycle()) {
+                *byte ^= k;
+            }
+        });
+        Ok(())
+    }
+
+    /// Returns a new resource holding `n` back-to-back copies of the bytes.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`RefinementError::EmptyBuffer`] if `n == 0`, since the result
+    /// would be empty, and [`RefinementError::CapacityOverflow`] if
+    /// `len() * n` overflows or exceeds `isize::MAX`.
+    pub fn repeat(&self, n: usize) -> Result<Self, RefinementError> {
+        if n == 0 {
+            return Err(RefinementError::EmptyBuffer);
+        }
+        let total = self
+            .len()
+            .checked_mul(n)
+            .filter(|&total| total <= isize::MAX as usize)
+            .ok_or(RefinementError::CapacityOverflow)?;
+        let mut tiled = Vec::with_capacity(total);
+        for _ in 0..n {
+            tiled.extend_from_slice(self.as_slice());
+        }
+        Self::refine(tiled)
+    }
+
+    /// Reverses the order of the bytes in place.
+    ///
+    /// Does not allocate when this is the only handle to the buffer; a shared
+    /// buffer is copied first so other clones are unaffected.
+    pub fn reverse_inplace(&mut self) {
+        self.modify(|buf| buf.reverse());
+    }
+
+    /// Returns a new resource with the bytes in reverse order.
+    pub fn reversed(&self) -> Self {
+        self.iter().rev().copied().collect()
+    }
+
+    /// Rotates the bytes in place so the first `n % len` bytes move to the
+    /// end.
+    ///
+    /// A no-op for empty resources and multiples of `len()`. Copies the bytes
+    /// first if the buffer is shared.
+    pub fn rotate_left(&mut self, n: usize) {
+        let len = self.len();
+        if len == 0 || n.is_multiple_of(len) {
+            return;
+        }
+        self.modify(|buf| buf.rotate_left(n % len));
+    }
+
+    /// Rotates the bytes in place so the last `n % len` bytes move to the
+    /// front.
+    ///
+    /// A no-op for empty resources and multiples of `len()`. Copies the bytes
+    /// first if the buffer is shared.
+    pub fn rotate_right(&mut self, n: usize) {
+        let len = self.len();
+        if len == 0 || n.is_multiple_of(len) {
+            return;
+        }
+        self.modify(|buf| buf.rotate_right(n % len));
+    }
+}
+
+impl PartialEq for RawResource {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.inner == other.inner
+    }
+}
+
+impl Eq for RawResource {}
+
+impl Hash for RawResource {
+    #[inline]
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.inner.hash(state);
+    }
+}
+
+impl PartialOrd for RawResource {
+    #[inline]
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for RawResource {
+    #[inline]
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.inner.cmp(&other.inner)
+    }
+}
+
+impl Deref for RawResource {
+    type Target = [u8];
+
+    #[inline]
+    fn deref(&self) -> &[u8] {
+        self.as_slice()
+    }
+}
+
+/// Indexes like a byte slice: `usize` yields a `u8` and every range kind
+/// (`a..b`, `a..`, `..b`, `..`, inclusive forms) yields a `[u8]`.
+///
+/// Panics on out-of-bounds access, matching `[u8]`; see
+/// [`subslice`](RawResource::subslice) for a checked alternative.
+impl<I: SliceIndex<[u8]>> Index<I> for RawResource {
+    type Output = I::Output;
+
+    #[inline]
+    fn index(&self, index: I) -> &I::Output {
+        &self.as_slice()[index]
+    }
+}
+
+impl TryFrom<Vec<u8>> for RawResource {
+    type Error = RefinementError;
+
+    fn try_from(data: Vec<u8>) -> Result<Self, Self::Error> {
+        Self::refine(data)
+    }
+}
+
+/// Copies the slice into a new heap allocation.
+impl From<&[u8]> for RawResource {
+    fn from(data: &[u8]) -> Self {
+        Self::from_bytes(Bytes::copy_from_slice(data))
+    }
+}
+
+/// Copies the UTF-8 bytes of the string into a new heap allocation.
+impl From<&str> for RawResource {
+    fn from(data: &str) -> Self {
+        Self::from(data.as_bytes())
+    }
+}
+
+impl FromIterator<u8> for RawResource {
+    fn from_iter<I: IntoIterator<Item = u8>>(iter: I) -> Self {
+        Self::from_bytes(iter.into_iter().collect::<Vec<u8>>().into())
+    }
+}
+
+/// Appends bytes, copying the buffer first if it is shared with other clones.
+impl Extend<u8> for RawResource {
+    fn extend<I: IntoIterator<Item = u8>>(&mut self, iter: I) {
+        self.modify(|buf| buf.extend(iter));
+    }
+}
+
+impl From<RawResource> for Vec<u8> {
+    fn from(resource: RawResource) -> Self {
+        resource.into_vec()
+    }
+}
+
+impl AsRef<[u8]> for RawResource {
+    #[inline]
+    fn as_ref(&self) -> &[u8] {
+        self.as_slice()
+    }
+}
+
+impl Borrow<[u8]> for RawResource {
+    #[inline]
+    fn borrow(&self) -> &[u8] {
+        self.as_slice()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_refine_success() {
+        let data = alloc::vec![1, 2, 3, 4, 5];
+        let resource = RawResource::refine(data).expect("should succeed");
+        assert_eq!(resource.len(), 5);
+        assert!(!resource.is_empty());
+    }
+
+    #[test]
+    fn test_refine_empty_success() {
+        let data: Vec<u8> = alloc::vec![];
+        let resource = RawResource::refine(data).expect("should succeed now");
+        assert!(resource.is_empty());
+        assert_eq!(resource.len(), 0);
+    }
+
+    #[test]
+    fn test_as_slice() {
+        let data = alloc::vec![10, 20, 30];
+        let resource = RawResource::refine(data).expect("should succeed");
+        let slice = resource.as_slice();
+        assert_eq!(slice, &[10, 20, 30]);
+        assert_eq!(resource.as_bytes(), slice);
+    }
+
+    #[test]
+    fn test_deref_slice_access() {
+        fn sum(bytes: &[u8]) -> u32 {
+            bytes.iter().map(|&b| u32::from(b)).sum()
+        }
+
+        let resource = RawResource::refine(alloc::vec![1, 2, 3, 4, 5]).expect("should succeed");
+        assert_eq!(&resource[2..4], &[3, 4]);
+        assert_eq!(resource[0], 1);
+        assert_eq!(resource.iter().count(), 5);
+        assert_eq!(sum(&resource), 15);
+    }
+
+    #[test]
+    fn test_clone_is_independent() {
+        let original = RawResource::refine(alloc::vec![1, 2, 3]).expect("should succeed");
+        let mut clone = original.clone().into_mut();
+        clone[0] = 42;
+        assert_eq!(original.as_slice(), &[1, 2, 3]);
+        assert_eq!(clone.as_slice(), &[42, 2, 3]);
+        drop(clone);
+        assert_eq!(original.as_slice(), &[1, 2, 3]);
+    }
+
+    #[test]
+    fn test_deep_clone_allocates() {
+        let original = RawResource::refine(alloc::vec![1, 2, 3]).expect("should succeed");
+        let copy = original.deep_clone();
+        assert_eq!(copy, original);
+        assert_ne!(copy.as_ptr(), original.as_ptr());
+        drop(original);
+        assert_eq!(copy.as_slice(), &[1, 2, 3]);
+    }
+
+    #[test]
+    fn test_ordering_and_hash() {
+        use core::hash::{Hash, Hasher};
+
+        #[derive(Default)]
+        struct Fnv(u64);
+
+        impl Hasher for Fnv {
+            fn finish(&self) -> u64 {
+                self.0
+            }
+
+            fn write(&mut self, bytes: &[u8]) {
+                for &b in bytes {
+                    self.0 = (self.0 ^ u64::from(b)).wrapping_mul(0x100_0000_01b3);
+                }
+            }
+        }
+
+        fn hash_of<T: Hash>(value: &T) -> u64 {
+            let mut hasher = Fnv::default();
+            value.hash(&mut hasher);
+            hasher.finish()
+        }
+
+        let a = RawResource::refine(alloc::vec![1, 2]).expect("should succeed");
+        let b = RawResource::refine(alloc::vec![1, 3]).expect("should succeed");
+        let c = RawResource::refine(alloc::vec![1, 2, 0]).expect("should succeed");
+        assert!(a < b);
+        assert!(a < c);
+        assert!(c < b);
+        assert_eq!(hash_of(&a), hash_of(&a.deep_clone()));
+        assert_ne!(hash_of(&a), hash_of(&b));
+    }
+
+    #[test]
+    fn test_as_ref_and_borrow() {
+        use alloc::collections::BTreeMap;
+
+        fn total_len<T: AsRef<[u8]>>(items: &[T]) -> usize {
+            items.iter().map(|item| item.as_ref().len()).sum()
+        }
+
+        let a = RawResource::refine(alloc::vec![1, 2, 3]).expect("should succeed");
+        let b = RawResource::refine(alloc::vec![4]).expect("should succeed");
+        assert_eq!(total_len(&[a.clone(), b.clone()]), 4);
+
+        let mut map = BTreeMap::new();
+        map.insert(a, "abc");
+        map.insert(b, "d");
+        assert_eq!(map.get(&[1u8, 2, 3][..]), Some(&"abc"));
+        assert_eq!(map.get(&[9u8][..]), None);
+    }
+
+    #[test]
+    fn test_try_from_vec() {
+        let resource = RawResource::try_from(alloc::vec![7, 8, 9]).expect("should succeed");
+        assert_eq!(resource.as_slice(), &[7, 8, 9]);
+    }
+
+    #[test]
+    fn test_into_vec_round_trip() {
+        let data = alloc::vec![1, 2, 3, 4];
+        let ptr = data.as_ptr();
+        let resource = RawResource::refine(data).expect("should succeed");
+        let vec = resource.into_vec();
+        assert_eq!(vec, [1, 2, 3, 4]);
+        assert_eq!(vec.as_ptr(), ptr);
+    }
+
+    #[test]
+    fn test_into_vec_shared_copies() {
+        let resource = RawResource::refine(alloc::vec![1, 2, 3]).expect("should succeed");
+        let other = resource.clone();
+        let vec: Vec<u8> = resource.into();
+        assert_eq!(vec, other.as_slice());
+        assert_ne!(vec.as_ptr(), other.as_ptr());
+    }
+
+    #[test]
+    fn test_from_slice_and_str() {
+        let literal: &[u8] = &[0xCA, 0xFE];
+        let resource = RawResource::from(literal);
+        assert_eq!(resource.as_slice(), literal);
+        assert_ne!(resource.as_ptr(), literal.as_ptr());
+
+        let text = RawResource::from("héllo");
+        assert_eq!(text.as_slice(), "héllo".as_bytes());
+        assert!(RawResource::from("").is_empty());
+    }
+
+    #[test]
+    fn test_from_static_is_zero_copy() {
+        static TABLE: [u8; 4] = [0x89, b'P', b'N', b'G'];
+        let resource = RawResource::from_static(&TABLE).expect("should succeed");
+        assert_eq!(resource.as_ptr(), TABLE.as_ptr());
+        assert_eq!(resource.len(), 4);
+
+        let mut copy = resource.clone().into_mut();
+        copy[0] = 0;
+        assert_ne!(copy.as_ptr(), TABLE.as_ptr());
+        assert_eq!(TABLE[0], 0x89);
+        drop(resource);
+    }
+
+    #[test]
+    fn test_collect_from_iterator() {
+        let resource: RawResource = (1u8..=4).collect();
+        assert_eq!(resource.as_slice(), &[1, 2, 3, 4]);
+
+        let empty: RawResource = core::iter::empty().collect();
+        assert!(empty.is_empty());
+    }
+
+    #[test]
+    fn test_extend() {
+        let mut resource = RawResource::refine(alloc::vec![1, 2]).expect("should succeed");
+        let shared = resource.clone();
+        resource.extend([3, 4]);
+        assert_eq!(resource.as_slice(), &[1, 2, 3, 4]);
+        assert_eq!(shared.as_slice(), &[1, 2]);
+
+        let mut from_static = RawResource::from_static(b"ab").expect("should succeed");
+        from_static.extend(*b"cd");
+        assert_eq!(from_static.as_slice(), b"abcd");
+    }
+
+    #[test]
+    fn test_split_off() {
+        let resource = RawResource::refine(alloc::vec![1, 2, 3, 4, 5]).expect("should succeed");
+        let (head, tail) = resource.split_off(2).expect("should succeed");
+        assert_eq!(head.as_slice(), &[1, 2]);
+        assert_eq!(tail.as_slice(), &[3, 4, 5]);
+        drop(head);
+        assert_eq!(tail.as_slice(), &[3, 4, 5]);
+    }
+
+    #[test]
+    fn test_split_off_edges() {
+        let resource = RawResource::refine(alloc::vec![1, 2, 3]).expect("should succeed");
+        let (head, tail) = resource.clone().split_off(0).expect("should succeed");
+        assert!(head.is_empty());
+        assert_eq!(tail, resource);
+
+        let (head, tail) = resource.clone().split_off(3).expect("should succeed");
+        assert_eq!(head, resource);
+        assert!(tail.is_empty());
+
+        assert_eq!(
+            resource.split_off(4),
+            Err(RefinementError::OutOfBounds { index: 4, len: 3 })
+        );
+    }
+
+    #[test]
+    fn test_truncate() {
+        let mut resource = RawResource::refine(alloc::vec![1, 2, 3, 4]).expect("should succeed");
+        let ptr = resource.as_ptr();
+        resource.truncate(2).expect("in range");
+        assert_eq!(resource.as_slice(), &[1, 2]);
+        assert_eq!(resource.as_ptr(), ptr);
+        assert_eq!(
+            resource.truncate(3),
+            Err(RefinementError::OutOfBounds { index: 3, len: 2 })
+        );
+        resource.truncate(0).expect("in range");
+        assert!(resource.is_empty());
+    }
+
+    #[test]
+    fn test_resize() {
+        let mut resource = RawResource::refine(alloc::vec![1, 2]).expect("should succeed");
+        let shared = resource.clone();
+        resource.resize(5, 0xFF).expect("should succeed");
+        assert_eq!(resource.as_slice(), &[1, 2, 0xFF, 0xFF, 0xFF]);
+        assert_eq!(shared.as_slice(), &[1, 2]);
+        resource.resize(1, 0).expect("should succeed");
+        assert_eq!(resource.as_slice(), &[1]);
+        assert_eq!(
+            resource.resize(usize::MAX, 0),
+            Err(RefinementError::CapacityOverflow)
+        );
+    }
+
+    #[test]
+    fn test_fill_and_zeroize() {
+        let mut resource = RawResource::refine(alloc::vec![1, 2, 3]).expect("should succeed");
+        resource.fill(0xAA);
+        assert_eq!(resource.as_slice(), &[0xAA; 3]);
+        resource.zeroize();
+        assert!(resource.iter().all(|&b| b == 0));
+        assert_eq!(resource.len(), 3);
+    }
+
+    #[test]
+    fn test_zeroize_shared_leaves_clone() {
+        let mut resource = RawResource::from("secret");
+        let other = resource.clone();
+        resource.zeroize();
+        assert_eq!(resource.as_slice(), &[0; 6]);
+        assert_eq!(other.as_slice(), b"secret");
+    }
+
+    #[test]
+    fn test_copy_from_slice() {
+        let mut resource = RawResource::refine(alloc::vec![0; 4]).expect("should succeed");
+        resource
+            .copy_from_slice(&[9, 8, 7, 6])
+            .expect("same length");
+        assert_eq!(resource.as_slice(), &[9, 8, 7, 6]);
+        assert_eq!(
+            resource.copy_from_slice(&[1, 2]),
+            Err(RefinementError::LengthMismatch {
+                expected: 4,
+                actual: 2
+            })
+        );
+        assert_eq!(resource.as_slice(), &[9, 8, 7, 6]);
+    }
+
+    #[test]
+    fn test_with_capacity_then_write() {
+        let resource = RawResource::with_capacity(6).expect("should succeed");
+        assert_eq!(resource.as_slice(), &[0; 6]);
+
+        let mut buf = resource.into_mut();
+        let mut writer = ResourceWriter::new(&mut buf);
+        writer.write_u16_be(0xCAFE).expect("fits");
+        assert_eq!(buf.freeze().as_slice(), &[0xCA, 0xFE, 0, 0, 0, 0]);
+
+        assert!(
+            RawResource::with_capacity(0)
+                .expect("should succeed")
+                .is_empty()
+        );
+        assert_eq!(
+            RawResource::with_capacity(usize::MAX),
+            Err(RefinementError::CapacityOverflow)
+        );
+    }
+
+    #[test]
+    fn test_leak_and_reclaim() {
+        let mut data = Vec::with_capacity(8);
+        data.extend_from_slice(&[1, 2, 3]);
+        let resource = RawResource::refine(data).expect("should succeed");
+        let (ptr, len, cap) = resource.leak();
+        assert_eq!(len, 3);
+        assert!(cap >= len);
+
+        // SAFETY: parts come straight from `leak` and are reclaimed once.
+        let reclaimed = unsafe { RawResource::from_leaked_ptr(ptr, len, cap) }.expect("valid");
+        assert_eq!(reclaimed.as_slice(), &[1, 2, 3]);
+        assert_eq!(reclaimed.as_ptr(), ptr);
+    }
+
+    #[test]
+    fn test_from_leaked_ptr_rejects_bad_parts() {
+        // SAFETY: both calls are rejected before any memory is touched.
+        unsafe {
+            assert_eq!(
+                RawResource::from_leaked_ptr(core::ptr::null_mut(), 0, 0),
+                Err(RefinementError::InvalidRawParts)
+            );
+            let mut byte = 0u8;
+            assert_eq!(
+                RawResource::from_leaked_ptr(&mut byte, 2, 1),
+                Err(RefinementError::InvalidRawParts)
+            );
+        }
+    }
+
+    #[test]
+    fn test_refine_with_capacity_keeps_headroom() {
+        let mut resource =
+            RawResource::refine_with_capacity(alloc::vec![1, 2], 64).expect("should succeed");
+        assert_eq!(resource.as_slice(), &[1, 2]);
+        let ptr = resource.as_ptr();
+        resource.resize(64, 0).expect("should succeed");
+        assert_eq!(resource.as_ptr(), ptr);
+        assert_eq!(&resource[..3], &[1, 2, 0]);
+
+        assert_eq!(
+            RawResource::refine_with_capacity(alloc::vec![1], usize::MAX),
+            Err(RefinementError::CapacityOverflow)
+        );
+    }
+
+    #[test]
+    fn test_chunks() {
+        let resource = RawResource::refine((0..7).collect()).expect("should succeed");
+        let chunks: Vec<_> = resource.chunks(3).collect();
+        assert_eq!(chunks.len(), 3);
+        assert_eq!(&*chunks[0], &[0, 1, 2]);
+        assert_eq!(&*chunks[1], &[3, 4, 5]);
+        assert_eq!(&*chunks[2], &[6]);
+        assert_eq!(chunks[1].as_ptr(), resource[3..].as_ptr());
+        assert_eq!(resource.chunks(7).len(), 1);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_chunks_zero_size_panics() {
+        let resource = RawResource::from("abc");
+        let _ = resource.chunks(0);
+    }
+
+    #[test]
+    fn test_windows() {
+        let resource = RawResource::refine(alloc::vec![1, 2, 3, 4]).expect("should succeed");
+        let windows: Vec<_> = resource.windows(3).collect();
+        assert_eq!(windows.len(), 2);
+        assert_eq!(&*windows[0], &[1, 2, 3]);
+        assert_eq!(&*windows[1], &[2, 3, 4]);
+        assert_eq!(windows[1].as_ptr(), resource[1..].as_ptr());
+        assert_eq!(resource.windows(5).count(), 0);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_windows_zero_size_panics() {
+        let resource = RawResource::from("abc");
+        let _ = resource.windows(0);
+    }
+
+    #[test]
+    fn test_find_byte_and_slice() {
+        let resource = RawResource::from("GET / HTTP/1.1\r\nHost: a\r\n\r\n");
+        assert_eq!(resource.find_byte(b' '), Some(3));
+        assert_eq!(resource.find_byte(b'G'), Some(0));
+        assert_eq!(resource.find_byte(b'#'), None);
+        assert_eq!(resource.find_slice(b"\r\n\r\n"), Some(23));
+        assert_eq!(resource.find_slice(b"\r\n"), Some(14));
+        assert_eq!(resource.find_slice(b"HTTP/2"), None);
+        assert_eq!(resource.find_slice(b""), Some(0));
+        assert_eq!(RawResource::from("ab").find_slice(b"abc"), None);
+    }
+
+    #[test]
+    fn test_rfind_byte_and_slice() {
+        let resource = RawResource::from("xab-ab-abx");
+        assert_eq!(resource.rfind_byte(b'x'), Some(9));
+        assert_eq!(resource.rfind_byte(b'-'), Some(6));
+        assert_eq!(RawResource::from("xyz").rfind_byte(b'x'), Some(0));
+        assert_eq!(resource.rfind_byte(b'#'), None);
+
+        assert_eq!(resource.rfind_slice(b"ab"), Some(7));
+        assert_eq!(resource.rfind_slice(b"xab"), Some(0));
+        assert_eq!(resource.rfind_slice(b"abx"), Some(7));
+        assert_eq!(resource.rfind_slice(b"ba"), None);
+        assert_eq!(resource.rfind_slice(b""), Some(10));
+    }
+
+    #[test]
+    fn test_contains_starts_ends() {
+        let resource = RawResource::from("\x7fELF payload");
+        assert!(resource.starts_with(b"\x7fELF"));
+        assert!(!resource.starts_with(b"ELF"));
+        assert!(resource.ends_with(b"load"));
+        assert!(!resource.ends_with(b"\x7fELF"));
+        assert!(resource.contains_slice(b"pay"));
+        assert!(!resource.contains_slice(b"paid"));
+        assert!(resource.contains_slice(b""));
+    }
+
+    #[test]
+    fn test_split_on_byte_c_strings() {
+        let resource = RawResource::from("alpha\0beta\0\0gamma\0");
+        let segments: Vec<_> = resource.split_on_byte(0).collect();
+        assert_eq!(segments.len(), 5);
+        assert_eq!(&*segments[0], b"alpha");
+        assert_eq!(&*segments[1], b"beta");
+        assert!(segments[2].is_empty());
+        assert_eq!(&*segments[3], b"gamma");
+        assert!(segments[4].is_empty());
+    }
+
+    #[test]
+    fn test_split_on_byte_http_headers() {
+        let resource = RawResource::from("Host: a\r\nAccept: */*\r\n\r\n");
+        let lines: Vec<&[u8]> = resource
+            .split_on_byte(b'\n')
+            .map(|line| {
+                line.as_slice()
+                    .strip_suffix(b"\r")
+                    .unwrap_or(line.as_slice())
+            })
+            .collect();
+        assert_eq!(lines, [&b"Host: a"[..], b"Accept: */*", b"", b""]);
+
+        let single = RawResource::from("no delimiter");
+        assert_eq!(single.split_on_byte(b'\n').count(), 1);
+    }
+
+    #[test]
+    fn test_trim_bytes() {
+        let resource = RawResource::from("  \tlog line\r\n");
+        let ws = |b: u8| b.is_ascii_whitespace();
+        assert_eq!(&*resource.trim_bytes(ws), b"log line");
+        assert_eq!(&*resource.trim_leading(ws), b"log line\r\n");
+        assert_eq!(&*resource.trim_trailing(ws), b"  \tlog line");
+        assert_eq!(resource.trim_bytes(ws).as_ptr(), resource[3..].as_ptr());
+
+        let padding = RawResource::refine(alloc::vec![0, 0, 0]).expect("should succeed");
+        assert!(padding.trim_bytes(|b| b == 0).is_empty());
+        assert!(padding.trim_leading(|b| b == 0).is_empty());
+        assert!(padding.trim_trailing(|b| b == 0).is_empty());
+    }
+
+    #[test]
+    fn test_xor_mask_inplace() {
+        // WebSocket masking example from RFC 6455, section 5.7.
+        let mut frame = RawResource::from("Hello");
+        let key = [0x37, 0xfa, 0x21, 0x3d];
+        frame.xor_mask_inplace(&key).expect("non-empty key");
+        assert_eq!(frame.as_slice(), &[0x7f, 0x9f, 0x4d, 0x51, 0x58]);
+        frame.xor_mask_inplace(&key).expect("non-empty key");
+        assert_eq!(frame.as_slice(), b"Hello");
+
+        assert_eq!(
+            frame.xor_mask_inplace(&[]),
+            Err(RefinementError::EmptyBuffer)
+        );
+    }
+
+    #[test]
+    fn test_refine_zeroed() {
+        let resource = RawResource::refine_zeroed(32).expect("should succeed");
+        assert_eq!(resource.len(), 32);
+        assert!(resource.iter().all(|&b| b == 0));
+        assert!(
+            RawResource::refine_zeroed(0)
+                .expect("should succeed")
+                .is_empty()
+        );
+        assert_eq!(
+            RawResource::refine_zeroed(usize::MAX),
+            Err(RefinementError::CapacityOverflow)
+        );
+    }
+
+    #[test]
+    fn test_refine_uninitialized_write_then_read() {
+        // SAFETY: every byte is written through a raw pointer before the
+        // resource is read.
+        let resource = unsafe {
+            let mut buf = RawResource::refine_uninitialized(16)
+                .expect("should succeed")
+                .into_mut();
+            let ptr = buf.as_mut_ptr();
+            for i in 0..16 {
+                ptr.add(i).write(i as u8);
+            }
+            buf.freeze()
+        };
+        assert!(resource.iter().copied().eq(0..16));
+        assert_eq!(
+            // SAFETY: the allocation fails, so no bytes exist to be read.
+            unsafe { RawResource::refine_uninitialized(usize::MAX) },
+            Err(RefinementError::CapacityOverflow)
+        );
+    }
+
+    #[test]
+    fn test_repeat() {
+        let template = RawResource::from(&[0xAB, 0xCD][..]);
+        let tiled = template.repeat(3).unwrap();
+        assert_eq!(tiled.as_slice(), &[0xAB, 0xCD, 0xAB, 0xCD, 0xAB, 0xCD]);
+        assert_eq!(template.repeat(1).unwrap(), template);
+
+        assert_eq!(template.repeat(0), Err(RefinementError::EmptyBuffer));
+        assert_eq!(
+            template.repeat(usize::MAX),
+            Err(RefinementError::CapacityOverflow)
+        );
+    }
+
+    #[test]
+    fn test_subslice_and_range_index() {
+        let resource = RawResource::from(&[0, 1, 2, 3, 4, 5, 6, 7][..]);
+        let view = resource.subslice(2, 6).unwrap();
+        assert_eq!(view.as_slice(), &[2, 3, 4, 5]);
+        assert_eq!(view.as_ptr(), resource[2..6].as_ptr());
+        assert!(resource.subslice(8, 8).unwrap().is_empty());
+
+        assert_eq!(
+            resource.subslice(2, 9),
+            Err(RefinementError::OutOfBounds { index: 9, len: 8 })
+        );
+        assert_eq!(
+            resource.subslice(5, 3),
+            Err(RefinementError::OutOfBounds { index: 5, len: 3 })
+        );
+    }
+
+    #[test]
+    fn test_index() {
+        let resource = RawResource::from(&[10, 11, 12, 13, 14][..]);
+        assert_eq!(resource[0], 10);
+        assert_eq!(&resource[1..3], &[11, 12]);
+        assert_eq!(&resource[3..], &[13, 14]);
+        assert_eq!(&resource[..2], &[10, 11]);
+        assert_eq!(&resource[..], resource.as_slice());
+        assert_eq!(&resource[..=1], &[10, 11]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_index_out_of_bounds_panics() {
+        let resource = RawResource::from(&[1, 2][..]);
+        let _ = resource[2];
+    }
+
+    #[test]
+    fn test_get_and_get_slice() {
+        let resource = RawResource::from(&[1, 2, 3][..]);
+        assert_eq!(resource.get(0), Some(1));
+        assert_eq!(resource.get(2), Some(3));
+        assert_eq!(resource.get(3), None);
+
+        assert_eq!(resource.get_slice(1..3), Some(&[2, 3][..]));
+        assert_eq!(resource.get_slice(3..3), Some(&[][..]));
+        assert_eq!(resource.get_slice(2..4), None);
+        #[allow(clippy::reversed_empty_ranges)]
+        let reversed = resource.get_slice(2..1);
+        assert_eq!(reversed, None);
+    }
+
+    #[test]
+    fn test_reverse() {
+        let original = RawResource::from(&[1, 2, 3, 4, 5][..]);
+        let reversed = original.reversed();
+        assert_eq!(reversed.as_slice(), &[5, 4, 3, 2, 1]);
+        assert_eq!(reversed.reversed(), original);
+
+        let mut owned = original.deep_clone();
+        let ptr = owned.as_ptr();
+        owned.reverse_inplace();
+        assert_eq!(owned, reversed);
+        assert_eq!(owned.as_ptr(), ptr);
+        owned.reverse_inplace();
+        assert_eq!(owned, original);
+    }
+
+    #[test]
+    fn test_rotate() {
+        let original = RawResource::from(&[1, 2, 3, 4, 5][..]);
+        for n in [0, 5, 10] {
+            let mut resource = original.clone();
+            resource.rotate_left(n);
+            assert_eq!(resource, original);
+            resource.rotate_right(n);
+            assert_eq!(resource, original);
+        }
+
+        let mut resource = original.clone();
+        resource.rotate_left(2);
+        assert_eq!(resource.as_slice(), &[3, 4, 5, 1, 2]);
+        resource.rotate_right(2);
+        assert_eq!(resource, original);
+
+        resource.rotate_left(7);
+        assert_eq!(resource.as_slice(), &[3, 4, 5, 1, 2]);
+        resource.rotate_right(12);
+        assert_eq!(resource, original);
+
+        let mut empty = RawResource::from(&[][..]);
+        empty.rotate_left(3);
+        assert!(empty.is_empty());
+    }
+
+    #[test]
+    fn test_count_byte_and_slice() {
+        let resource = RawResource::from("a,b,,c");
+        assert_eq!(resource.count_byte(b','), 3);
+        assert_eq!(resource.count_byte(b'z'), 0);
+        assert_eq!(resource.count_slice(b",,"), 1);
+        assert_eq!(resource.count_slice(b""), 7);
+
+        // Non-overlapping: "aaaa" holds "aa" at 0, 1 and 2, but only 0 and 2
+        // are counted.
+        let run = RawResource::from("aaaa");
+        assert_eq!(run.count_slice(b"aa"), 2);
+        assert_eq!(run.count_slice(b"aaa"), 1);
+        assert_eq!(run.count_slice(b"aaaaa"), 0);
+    }
+
+    #[test]
+    fn test_borrow_view() {
+        let resource = RawResource::from(&[1, 2, 3][..]);
+        let view = resource.borrow_view();
+        assert_eq!(view.as_slice(), resource.as_slice());
+        assert_eq!(view.as_ptr(), resource.as_ptr());
+        assert_eq!(view, ResourceView::from(&resource));
+    }
+
+    #[test]
+    fn test_try_extend() {
+        let mut resource = RawResource::refine_with_capacity(alloc::vec![1, 2], 8).unwrap();
+        let ptr = resource.as_ptr();
+        resource.try_extend(&[3, 4]).unwrap();
+        assert_eq!(resource.as_slice(), &[1, 2, 3, 4]);
+        assert_eq!(resource.as_ptr(), ptr);
+
+        let snapshot = resource.clone();
+        resource.try_extend(&[5]).unwrap();
+        assert_eq!(resource.as_slice(), &[1, 2, 3, 4, 5]);
+        assert_eq!(snapshot.as_slice(), &[1, 2, 3, 4]);
+
+        assert_eq!(resource.try_extend(&[]), Err(RefinementError::EmptyBuffer));
+        assert_eq!(resource.len(), 5);
+    }
+
+    #[test]
+    fn test_prepend() {
+        let mut packet = RawResource::from("payload");
+        let body = packet.clone();
+        packet.prepend(&[0x02, 0x07]).unwrap();
+        assert_eq!(packet.as_slice(), b"\x02\x07payload");
+        assert_eq!(body.as_slice(), b"payload");
+
+        packet.prepend(&[]).unwrap();
+        assert_eq!(packet.len(), 9);
+
+        let mut empty = RawResource::from(&[][..]);
+        empty.prepend(b"hdr").unwrap();
+        assert_eq!(empty.as_slice(), b"hdr");
+    }
+
+    #[test]
+    fn test_generation_counts_mutations() {
+        let mut resource = RawResource::from(&[1, 2, 3, 4][..]);
+        assert_eq!(resource.generation(), 0);
+
+        resource.fill(0);
+        resource.xor_mask_inplace(&[0xFF]).unwrap();
+        resource.resize(6, 0).unwrap();
+        resource.truncate(5).unwrap();
+        resource.prepend(&[9]).unwrap();
+        assert_eq!(resource.generation(), 5);
+
+        let snapshot = resource.clone();
+        assert_eq!(snapshot.generation(), 5);
+        resource.zeroize();
+        assert_eq!(resource.generation(), 6);
+        assert_eq!(snapshot.generation(), 5);
+
+        let _ = resource.find_byte(0);
+        let _ = resource.borrow_view();
+        assert_eq!(resource.generation(), 6);
+    }
+
+    #[test]
+    fn test_generation_survives_mutable_round_trip() {
+        let mut resource = RawResource::from(&[1, 2, 3][..]);
+        resource.fill(0);
+        let before = resource.generation();
+
+        let untouched = resource.clone().into_mut().freeze();
+        assert_eq!(untouched.generation(), before);
+
+        let mut buf = resource.into_mut();
+        assert_eq!(buf.generation(), before);
+        buf[0] = 0xFF;
+        let after = buf.freeze();
+        assert!(after.generation() > before);
+        assert_eq!(after.as_slice(), &[0xFF, 0, 0]);
+    }
+
+    #[test]
+    fn test_generation_ignored_by_comparison() {
+        let mut a = RawResource::from(&[5, 5][..]);
+        a.fill(5);
+        let b = RawResource::from(&[5, 5][..]);
+        assert_ne!(a.generation(), b.generation());
+        assert_eq!(a, b);
+        assert_eq!(a.cmp(&b), core::cmp::Ordering::Equal);
+    }
+
+    #[test]
+    fn test_try_merge_in_place() {
+        let mut head = RawResource::refine_with_capacity(alloc::vec![1, 2], 16).unwrap();
+        let ptr = head.as_ptr();
+        head.try_merge_in_place(RawResource::from(&[3, 4, 5][..]))
+            .unwrap();
+        assert_eq!(head.as_slice(), &[1, 2, 3, 4, 5]);
+        assert_eq!(head.as_ptr(), ptr);
+
+        head.try_merge_in_place(RawResource::from(&[][..])).unwrap();
+        assert_eq!(head.len(), 5);
+
+        let shared = head.clone();
+        head.try_merge_in_place(RawResource::from(&[6][..]))
+            .unwrap();
+        assert_eq!(head.as_slice(), &[1, 2, 3, 4, 5, 6]);
+        assert_eq!(shared.as_slice(), &[1, 2, 3, 4, 5]);
+    }
+
+    #[test]
+    fn test_copy_to_slice_and_offset() {
+        let resource = RawResource::from(&[1, 2, 3][..]);
+        let mut exact = [0u8; 3];
+        resource.copy_to_slice(&mut exact).unwrap();
+        assert_eq!(exact, [1, 2, 3]);
+        assert_eq!(
+            resource.copy_to_slice(&mut [0u8; 4]),
+            Err(RefinementError::LengthMismatch {
+                expected: 3,
+                actual: 4
+            })
+        );
+
+        let mut frame = [0xFFu8; 6];
+        resource.copy_to_offset(&mut frame, 2).unwrap();
+        assert_eq!(frame, [0xFF, 0xFF, 1, 2, 3, 0xFF]);
+        resource.copy_to_offset(&mut frame, 3).unwrap();
+        assert_eq!(
+            resource.copy_to_offset(&mut frame, 4),
+            Err(RefinementError::OutOfBounds { index: 7, len: 6 })
+        );
+        assert!(resource.copy_to_offset(&mut frame, usize::MAX).is_err());
+    }
+
+    #[test]
+    fn test_refinement_error_display() {
+        use alloc::string::ToString;
+
+        fn assert_error<E: core::error::Error>(_: &E) {}
+
+        let err = RefinementError::EmptyBuffer;
+        assert_error(&err);
+        assert_eq!(err.to_string(), "buffer is empty");
+        assert_eq!(
+            RefinementError::CapacityOverflow.to_string(),
+            "capacity overflow"
+        );
     }
 
     #[test]
@@ -0,0 +1,140 @@
+//! Copying concatenation of resources into a single contiguous buffer.
+
+use alloc::vec::Vec;
+
+use bytes::Bytes;
+
+use crate::{RawResource, RefinementError};
+
+/// Concatenates `a` and `b` into one contiguous resource.
+///
+/// `a`'s buffer is reused when it is uniquely owned and came from a `Vec`;
+/// otherwise both payloads are copied into a new allocation sized for the
+/// combined length. Both inputs are consumed.
+///
+/// # Errors
+///
+/// Returns [`RefinementError::CapacityOverflow`] if the combined length cannot
+/// be allocated.
+///
+/// # Example
+///
+/// ```
+/// use praborrow_logistics::{RawResource, merge};
+///
+/// let header = RawResource::from(&[0x01, 0x02][..]);
+/// let body = RawResource::from("payload");
+/// let frame = merge(header, body).unwrap();
+/// assert_eq!(frame.as_slice(), b"\x01\x02payload");
+/// ```
+pub fn merge(a: RawResource, b: RawResource) -> Result<RawResource, RefinementError> {
+    let total = a
+        .len()
+        .checked_add(b.len())
+        .ok_or(RefinementError::CapacityOverflow)?;
+    // A shared or static `a` is copied straight into the combined buffer
+    // rather than via `into_vec`, which would allocate exactly `a.len()` and
+    // then reallocate to append `b`.
+    let (mut merged, head) = match a.inner.try_into_mut() {
+        Ok(unique) => (Vec::from(unique), Bytes::new()),
+        Err(shared) => (Vec::new(), shared),
+    };
+    merged
+        .try_reserve_exact(total - merged.len())
+        .map_err(|_| RefinementError::CapacityOverflow)?;
+    merged.extend_from_slice(&head);
+    merged.extend_from_slice(b.as_slice());
+    RawResource::refine(merged)
+}
+
+/// Concatenates every resource in order into one contiguous resource.
+///
+/// The total length is computed up front so that exactly one buffer is
+/// allocated and each payload is copied into it once.
+///
+/// # Errors
+///
+/// Returns [`RefinementError::EmptyBuffer`] if `resources` yields nothing, or
+/// [`RefinementError::CapacityOverflow`] if the total length overflows.
+///
+/// # Example
+///
+/// ```
+/// use praborrow_logistics::{RawResource, concat};
+///
+/// let parts = ["GET ", "/ ", "HTTP/1.1"].map(RawResource::from);
+/// let line = concat(parts).unwrap();
+/// assert_eq!(line.as_slice(), b"GET / HTTP/1.1");
+/// ```
+pub fn concat(
+    resources: impl IntoIterator<Item = RawResource>,
+) -> Result<RawResource, RefinementError> {
+    let resources: Vec<RawResource> = resources.into_iter().collect();
+    if resources.is_empty() {
+        return Err(RefinementError::EmptyBuffer);
+    }
+    merge_all(&resources)
+}
+
+pub(crate) fn merge_all(segments: &[RawResource]) -> Result<RawResource, RefinementError> {
+    let total = segments
+        .iter()
+        .try_fold(0usize, |acc, segment| acc.checked_add(segment.len()))
+        .ok_or(RefinementError::CapacityOverflow)?;
+    let mut merged = Vec::new();
+    merged
+        .try_reserve_exact(total)
+        .map_err(|_| RefinementError::CapacityOverflow)?;
+    for segment in segments {
+        merged.extend_from_slice(segment.as_slice());
+    }
+    RawResource::refine(merged)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_merge_combinations() {
+        let cases: &[(&[u8], &[u8])] = &[
+            (&[], &[]),
+            (&[0xAA], &[]),
+            (&[], &[0xBB]),
+            (&[0xAA], &[0xBB]),
+            (&[1, 2, 3], &[4, 5, 6, 7]),
+        ];
+        for &(a, b) in cases {
+            let merged = merge(RawResource::from(a), RawResource::from(b)).unwrap();
+            assert_eq!(merged.len(), a.len() + b.len());
+            assert_eq!(&merged[..a.len()], a);
+            assert_eq!(&merged[a.len()..], b);
+        }
+    }
+
+    #[test]
+    fn test_merge_leaves_shared_inputs_intact() {
+        let a = RawResource::from(&[1, 2][..]);
+        let b = RawResource::from(&[3][..]);
+        let merged = merge(a.clone(), b.clone()).unwrap();
+        assert_eq!(merged.as_slice(), &[1, 2, 3]);
+        assert_eq!(a.as_slice(), &[1, 2]);
+        assert_eq!(b.as_slice(), &[3]);
+    }
+
+    #[test]
+    fn test_concat_counts() {
+        assert_eq!(concat([]), Err(RefinementError::EmptyBuffer));
+
+        let one = concat([RawResource::from(&[7][..])]).unwrap();
+        assert_eq!(one.as_slice(), &[7]);
+
+        let many = concat((0u8..100).map(|i| RawResource::from(&[i, i][..]))).unwrap();
+        assert_eq!(many.len(), 200);
+        assert!(
+            many.chunks_exact(2)
+                .enumerate()
+                .all(|(i, pair)| pair == [i as u8; 2])
+        );
+    }
+}
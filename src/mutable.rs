@@ -0,0 +1,350 @@
+//! Mutable counterpart of [`RawResource`].
+
+use alloc::vec::Vec;
+use bytes::BytesMut;
+use core::borrow::{Borrow, BorrowMut};
+use core::cmp::Ordering;
+use core::hash::{Hash, Hasher};
+use core::ops::{Deref, DerefMut};
+
+use crate::{RawResource, RefinementError};
+
+/// A uniquely owned, mutable byte resource.
+///
+/// `RawResource` is backed by reference-counted `bytes::Bytes`, so several
+/// clones may observe the same memory and it can never hand out `&mut [u8]`.
+/// `MutRawResource` is backed by `bytes::BytesMut` instead, which is always
+/// the sole owner of its allocation.
+///
+/// # Aliasing
+///
+/// Because the buffer has exactly one owner, the `&mut [u8]` returned by
+/// [`as_mut_slice`](Self::as_mut_slice) (or `DerefMut`) is the only live
+/// reference to those bytes for as long as the borrow lasts. Converting a
+/// shared `RawResource` with [`RawResource::into_mut`] copies the bytes when
+/// other clones still exist, so writes are never visible through another
+/// handle.
+///
+/// `Clone` copies the bytes into a new allocation, since two owners of one
+/// mutable buffer would violate the aliasing guarantee above.
+///
+/// Like `RawResource`, equality, ordering, and hashing compare only the
+/// bytes; the [`generation`](Self::generation) counter is ignored.
+#[derive(Clone)]
+pub struct MutRawResource {
+    inner: BytesMut,
+    generation: u64,
+}
+
+impl MutRawResource {
+    /// Refines raw data into a mutable resource without copying.
+    ///
+    /// # Errors
+    ///
+    /// Currently infallible, mirroring [`RawResource::refine`].
+    pub fn refine(data: Vec<u8>) -> Result<Self, RefinementError> {
+        RawResource::refine(data).map(RawResource::into_mut)
+    }
+
+    pub(crate) fn from_bytes_mut(inner: BytesMut, generation: u64) -> Self {
+        Self { inner, generation }
+    }
+
+    fn bump_generation(&mut self) {
+        self.generation = self.generation.wrapping_add(1);
+    }
+
+    /// Returns the generation carried over from [`RawResource::generation`].
+    ///
+    /// Every mutable access ([`as_mut_slice`](Self::as_mut_slice) and hence
+    /// `DerefMut` and [`ResourceWriter`](crate::ResourceWriter), as well as
+    /// [`as_mut_ptr`](Self::as_mut_ptr) and [`set_len`](Self::set_len))
+    /// advances it, and [`freeze`](Self::freeze) hands it back, so writes made
+    /// between `into_mut` and `freeze` are visible to stale-detection.
+    #[inline]
+    pub fn generation(&self) -> u64 {
+        self.generation
+    }
+
+    /// Returns the raw pointer to the resource data.
+    #[inline]
+    pub fn as_ptr(&self) -> *const u8 {
+        self.inner.as_ptr()
+    }
+
+    /// Returns the mutable raw pointer to the resource data.
+    #[inline]
+    pub fn as_mut_ptr(&mut self) -> *mut u8 {
+        self.bump_generation();
+        self.inner.as_mut_ptr()
+    }
+
+    /// Sets the length after foreign code has written into the allocation.
+    ///
+    /// Intended for FFI calls that fill a buffer through
+    /// [`as_mut_ptr`](Self::as_mut_ptr) and report how many bytes they wrote.
+    /// `RawResource` has no equivalent because its bytes are immutable.
+    ///
+    /// # Safety
+    ///
+    /// `new_len` must not exceed [`capacity`](Self::capacity), and every byte
+    /// in `[0, new_len)` must be initialized.
+    #[inline]
+    pub unsafe fn set_len(&mut self, new_len: usize) {
+        debug_assert!(new_len <= self.inner.capacity());
+        self.bump_generation();
+        // SAFETY: upheld by the caller.
+        unsafe { self.inner.set_len(new_len) }
+    }
+
+    /// Returns the length of the resource data in bytes.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.inner.len()
+    }
+
+    /// Returns `true` if the resource has zero length.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.inner.is_empty()
+    }
+
+    /// Returns the size of the underlying allocation in bytes.
+    ///
+    /// Always at least [`len`](Self::len). Spare capacity reserved by
+    /// [`RawResource::refine_with_capacity`] or left behind by
+    /// [`RawResource::truncate`] is visible here after a zero-copy
+    /// [`RawResource::into_mut`].
+    #[inline]
+    pub fn capacity(&self) -> usize {
+        self.inner.capacity()
+    }
+
+    /// Returns `capacity() - len()`, the bytes allocated but not in use.
+    #[inline]
+    pub fn excess_capacity(&self) -> usize {
+        self.inner.capacity() - self.inner.len()
+    }
+
+    /// Moves the bytes into an exactly sized allocation if there is spare
+    /// capacity.
+    pub fn shrink_to_fit(&mut self) {
+        if self.inner.capacity() > self.inner.len() {
+            self.inner = BytesMut::from(&self.inner[..]);
+        }
+    }
+
+    /// Returns a byte slice of the resource.
+    #[inline]
+    pub fn as_slice(&self) -> &[u8] {
+        &self.inner
+    }
+
+    /// Returns a mutable byte slice of the resource.
+    ///
+    /// The borrow is exclusive; see the type-level aliasing notes.
+    #[inline]
+    pub fn as_mut_slice(&mut self) -> &mut [u8] {
+        self.bump_generation();
+        &mut self.inner
+    }
+
+    /// Converts back into an immutable, shareable `RawResource` without copying.
+    ///
+    /// The resource keeps this handle's [`generation`](Self::generation).
+    pub fn freeze(self) -> RawResource {
+        RawResource {
+            inner: self.inner.freeze(),
+            generation: self.generation,
+        }
+    }
+}
+
+impl PartialEq for MutRawResource {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.inner == other.inner
+    }
+}
+
+impl Eq for MutRawResource {}
+
+impl Hash for MutRawResource {
+    #[inline]
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.inner.hash(state);
+    }
+}
+
+impl PartialOrd for MutRawResource {
+    #[inline]
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for MutRawResource {
+    #[inline]
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.inner.cmp(&other.inner)
+    }
+}
+
+impl Deref for MutRawResource {
+    type Target = [u8];
+
+    #[inline]
+    fn deref(&self) -> &[u8] {
+        self.as_slice()
+    }
+}
+
+impl DerefMut for MutRawResource {
+    #[inline]
+    fn deref_mut(&mut self) -> &mut [u8] {
+        self.as_mut_slice()
+    }
+}
+
+impl AsRef<[u8]> for MutRawResource {
+    #[inline]
+    fn as_ref(&self) -> &[u8] {
+        self.as_slice()
+    }
+}
+
+impl AsMut<[u8]> for MutRawResource {
+    #[inline]
+    fn as_mut(&mut self) -> &mut [u8] {
+        self.as_mut_slice()
+    }
+}
+
+impl Borrow<[u8]> for MutRawResource {
+    #[inline]
+    fn borrow(&self) -> &[u8] {
+        self.as_slice()
+    }
+}
+
+impl BorrowMut<[u8]> for MutRawResource {
+    #[inline]
+    fn borrow_mut(&mut self) -> &mut [u8] {
+        self.as_mut_slice()
+    }
+}
+
+impl From<RawResource> for MutRawResource {
+    fn from(resource: RawResource) -> Self {
+        resource.into_mut()
+    }
+}
+
+impl From<MutRawResource> for RawResource {
+    fn from(resource: MutRawResource) -> Self {
+        resource.freeze()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_mutate_in_place() {
+        let mut resource = MutRawResource::refine(alloc::vec![1, 2, 3]).expect("should succeed");
+        resource.as_mut_slice()[0] = 10;
+        resource[2] = 30;
+        assert_eq!(resource.as_slice(), &[10, 2, 30]);
+    }
+
+    #[test]
+    fn test_into_mut_unique_does_not_copy() {
+        let resource = RawResource::refine(alloc::vec![1, 2, 3]).expect("should succeed");
+        let ptr = resource.as_ptr();
+        let mut mutable = resource.into_mut();
+        assert_eq!(mutable.as_ptr(), ptr);
+        mutable[1] = 20;
+        let frozen = mutable.freeze();
+        assert_eq!(frozen.as_ptr(), ptr);
+        assert_eq!(frozen.as_slice(), &[1, 20, 3]);
+    }
+
+    #[test]
+    fn test_capacity() {
+        let resource = RawResource::refine_with_capacity(alloc::vec![1, 2, 3], 16).unwrap();
+        let mutable = resource.into_mut();
+        assert!(mutable.capacity() >= 16);
+        assert_eq!(mutable.excess_capacity(), mutable.capacity() - 3);
+
+        let mut truncated = RawResource::with_capacity(32).unwrap();
+        truncated.truncate(8).unwrap();
+        let mutable = truncated.into_mut();
+        assert_eq!(mutable.len(), 8);
+        assert_eq!(mutable.capacity(), 32);
+        assert_eq!(mutable.excess_capacity(), 24);
+    }
+
+    #[test]
+    fn test_shrink_to_fit() {
+        let mut resource = RawResource::with_capacity(32).unwrap();
+        resource.truncate(8).unwrap();
+        resource.shrink_to_fit();
+        let mut mutable = resource.into_mut();
+        assert_eq!(mutable.capacity(), mutable.len());
+        assert_eq!(mutable.as_slice(), &[0; 8]);
+
+        mutable.shrink_to_fit();
+        assert_eq!(mutable.capacity(), 8);
+
+        let mut spare =
+            MutRawResource::from(RawResource::refine_with_capacity(alloc::vec![7], 64).unwrap());
+        spare.shrink_to_fit();
+        assert_eq!(spare.capacity(), 1);
+        assert_eq!(spare.as_slice(), &[7]);
+    }
+
+    #[test]
+    fn test_shrink_to_fit_leaves_shared_buffer() {
+        let mut resource = RawResource::with_capacity(16).unwrap();
+        resource.truncate(4).unwrap();
+        let other = resource.clone();
+        resource.shrink_to_fit();
+        assert_eq!(resource.as_ptr(), other.as_ptr());
+        assert_eq!(resource, other);
+    }
+
+    #[test]
+    fn test_set_len_after_ffi_fill() {
+        /// Stands in for a C `read(fd, buf, cap)` that fills `n <= cap` bytes.
+        unsafe fn ffi_read(buf: *mut u8, cap: usize) -> usize {
+            let n = cap.min(5);
+            for i in 0..n {
+                // SAFETY: `i < cap`, which the caller guarantees is writable.
+                unsafe { buf.add(i).write(b'a' + i as u8) };
+            }
+            n
+        }
+
+        let mut resource = RawResource::refine_with_capacity(Vec::new(), 16)
+            .unwrap()
+            .into_mut();
+        assert_eq!(resource.len(), 0);
+        let cap = resource.capacity();
+        // SAFETY: the allocation has `cap` writable bytes, and `ffi_read`
+        // initializes the first `n` of them.
+        let n = unsafe { ffi_read(resource.as_mut_ptr(), cap) };
+        // SAFETY: `ffi_read` returns `n <= cap` and has initialized `[0, n)`.
+        unsafe { resource.set_len(n) };
+        assert_eq!(resource.as_slice(), b"abcde");
+    }
+
+    #[test]
+    fn test_into_mut_shared_copies() {
+        let original = RawResource::refine(alloc::vec![1, 2, 3]).expect("should succeed");
+        let mut mutable = original.clone().into_mut();
+        mutable[0] = 0xFF;
+        assert_eq!(original.as_slice(), &[1, 2, 3]);
+        assert_eq!(mutable.as_slice(), &[0xFF, 2, 3]);
+    }
+}
@@ -0,0 +1,70 @@
+//! Resources whose address must stay fixed, such as registered DMA buffers.
+
+use alloc::boxed::Box;
+use core::pin::Pin;
+
+use crate::RawResource;
+
+/// A `RawResource` pinned on the heap.
+///
+/// Once a buffer's address has been handed to hardware or foreign code it
+/// must not move. `PinnedResource` exposes no way to mutate or reallocate the
+/// bytes, so the pointer returned by [`as_ptr`](Self::as_ptr) stays valid and
+/// unchanged until the `PinnedResource` is dropped, even if the
+/// `PinnedResource` itself is moved.
+#[derive(Debug)]
+pub struct PinnedResource(Pin<Box<RawResource>>);
+
+impl PinnedResource {
+    /// Pins `resource` on the heap.
+    pub fn pin(resource: RawResource) -> Self {
+        Self(Box::pin(resource))
+    }
+
+    /// Returns a pointer to the first byte, stable for the lifetime of `self`.
+    #[inline]
+    pub fn as_ptr(&self) -> *const u8 {
+        self.0.as_ptr()
+    }
+
+    /// Returns the number of bytes.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    /// Returns `true` if the resource holds no bytes.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// Returns a shared reference to the pinned resource.
+    #[inline]
+    pub fn get_ref(&self) -> &RawResource {
+        self.0.as_ref().get_ref()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::vec::Vec;
+
+    #[test]
+    fn test_pinned_pointer_is_stable() {
+        let pinned = PinnedResource::pin(RawResource::from(&[0u8; 64][..]));
+        let ptr = pinned.as_ptr();
+
+        let mut moved = Vec::new();
+        moved.push(pinned);
+        moved.reserve(16);
+        let pinned = moved.pop().unwrap();
+        assert_eq!(pinned.as_ptr(), ptr);
+
+        let copy = pinned.get_ref().deep_clone();
+        assert_eq!(copy.as_slice(), pinned.get_ref().as_slice());
+        assert_eq!(pinned.as_ptr(), ptr);
+        assert_eq!(pinned.len(), 64);
+    }
+}
@@ -0,0 +1,209 @@
+//! Reuse of buffer allocations across acquire/release cycles.
+
+use alloc::vec::Vec;
+use bytes::BytesMut;
+use core::ops::{Deref, DerefMut};
+
+use crate::RawResource;
+
+/// A bounded free-list of buffers for repeated same-size allocations.
+///
+/// [`acquire`](Self::acquire) hands out a zero-filled resource, reusing a
+/// pooled allocation when one is large enough. [`release`](Self::release)
+/// returns a resource's allocation to the pool, unless the pool is full or the
+/// resource still shares its buffer with other clones.
+#[derive(Debug)]
+pub struct ResourcePool {
+    free: Vec<BytesMut>,
+    max_pooled: usize,
+}
+
+impl ResourcePool {
+    /// Creates an empty pool that keeps at most `max_pooled` buffers.
+    pub fn new(max_pooled: usize) -> Self {
+        Self {
+            free: Vec::new(),
+            max_pooled,
+        }
+    }
+
+    /// Returns the number of buffers currently held by the pool.
+    #[inline]
+    pub fn pooled(&self) -> usize {
+        self.free.len()
+    }
+
+    /// Returns the maximum number of buffers the pool will hold.
+    #[inline]
+    pub fn max_pooled(&self) -> usize {
+        self.max_pooled
+    }
+
+    /// Returns a resource of `len` zero bytes.
+    ///
+    /// Reuses the first pooled buffer whose capacity is at least `len`, and
+    /// allocates a new one otherwise. Reused bytes are zeroed, so no data from
+    /// a previous user is observable.
+    pub fn acquire(&mut self, len: usize) -> RawResource {
+        let mut buf = match self.free.iter().position(|buf| buf.capacity() >= len) {
+            Some(index) => self.free.swap_remove(index),
+            None => BytesMut::with_capacity(len),
+        };
+        buf.clear();
+        buf.resize(len, 0);
+        RawResource::from_bytes(buf.freeze())
+    }
+
+    /// Returns the allocation behind `resource` to the pool.
+    ///
+    /// The resource is simply dropped if the pool is full or if its buffer is
+    /// shared with other clones or not heap-owned (e.g. `from_static`).
+    pub fn release(&mut self, resource: RawResource) {
+        if self.free.len() >= self.max_pooled {
+            return;
+        }
+        if let Ok(buf) = resource.into_bytes().try_into_mut() {
+            self.free.push(buf);
+        }
+    }
+
+    /// Frees every pooled buffer.
+    pub fn drain(&mut self) {
+        self.free.clear();
+    }
+
+    /// Acquires a resource that is released back to this pool on drop.
+    pub fn acquire_guarded(&mut self, len: usize) -> RefineGuard<'_> {
+        let resource = self.acquire(len);
+        RefineGuard::new(resource, self)
+    }
+}
+
+/// A pool-backed resource that returns itself to its [`ResourcePool`] on drop.
+///
+/// Makes pooled buffers safe to use across early returns and `?`: however the
+/// scope is left, the allocation goes back to the pool.
+#[derive(Debug)]
+pub struct RefineGuard<'a> {
+    resource: Option<RawResource>,
+    pool: &'a mut ResourcePool,
+}
+
+impl<'a> RefineGuard<'a> {
+    /// Wraps `resource` so it is released to `pool` when the guard drops.
+    pub fn new(resource: RawResource, pool: &'a mut ResourcePool) -> Self {
+        Self {
+            resource: Some(resource),
+            pool,
+        }
+    }
+
+    /// Detaches the resource from the pool, so it is not released on drop.
+    pub fn into_inner(mut self) -> RawResource {
+        self.resource
+            .take()
+            .expect("guard holds a resource until dropped")
+    }
+}
+
+impl Deref for RefineGuard<'_> {
+    type Target = RawResource;
+
+    fn deref(&self) -> &RawResource {
+        self.resource
+            .as_ref()
+            .expect("guard holds a resource until dropped")
+    }
+}
+
+impl DerefMut for RefineGuard<'_> {
+    fn deref_mut(&mut self) -> &mut RawResource {
+        self.resource
+            .as_mut()
+            .expect("guard holds a resource until dropped")
+    }
+}
+
+impl Drop for RefineGuard<'_> {
+    fn drop(&mut self) {
+        if let Some(resource) = self.resource.take() {
+            self.pool.release(resource);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_acquire_reuses_released_buffer() {
+        let mut pool = ResourcePool::new(4);
+        let mut first = pool.acquire(512);
+        first.fill(0xAB);
+        let ptr = first.as_ptr();
+        pool.release(first);
+        assert_eq!(pool.pooled(), 1);
+
+        let second = pool.acquire(256);
+        assert_eq!(second.as_ptr(), ptr);
+        assert_eq!(second.len(), 256);
+        assert!(second.iter().all(|&b| b == 0));
+        assert_eq!(pool.pooled(), 0);
+    }
+
+    #[test]
+    fn test_acquire_allocates_when_too_small() {
+        let mut pool = ResourcePool::new(4);
+        pool.release(RawResource::refine_zeroed(16).expect("should succeed"));
+        let large = pool.acquire(64);
+        assert_eq!(large.len(), 64);
+        assert_eq!(pool.pooled(), 1);
+    }
+
+    #[test]
+    fn test_guard_releases_on_early_return() {
+        fn parse(pool: &mut ResourcePool, fail: bool) -> Result<u8, ()> {
+            let mut buf = pool.acquire_guarded(4);
+            buf.fill(7);
+            if fail {
+                return Err(());
+            }
+            Ok(buf[0])
+        }
+
+        let mut pool = ResourcePool::new(4);
+        assert_eq!(parse(&mut pool, true), Err(()));
+        assert_eq!(pool.pooled(), 1);
+        assert_eq!(parse(&mut pool, false), Ok(7));
+        assert_eq!(pool.pooled(), 1);
+    }
+
+    #[test]
+    fn test_guard_into_inner_detaches() {
+        let mut pool = ResourcePool::new(4);
+        let resource = pool.acquire_guarded(2).into_inner();
+        assert_eq!(resource.len(), 2);
+        assert_eq!(pool.pooled(), 0);
+    }
+
+    #[test]
+    fn test_release_respects_limit_and_sharing() {
+        let mut pool = ResourcePool::new(1);
+        let shared = pool.acquire(8);
+        let clone = shared.clone();
+        pool.release(shared);
+        assert_eq!(pool.pooled(), 0);
+        assert_eq!(clone.len(), 8);
+
+        pool.release(RawResource::from_static(b"static").expect("should succeed"));
+        assert_eq!(pool.pooled(), 0);
+
+        pool.release(clone);
+        pool.release(RawResource::refine_zeroed(8).expect("should succeed"));
+        assert_eq!(pool.pooled(), 1);
+
+        pool.drain();
+        assert_eq!(pool.pooled(), 0);
+    }
+}
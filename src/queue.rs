@@ -0,0 +1,120 @@
+//! Fixed-capacity FIFO byte queues.
+
+use crate::{RefinementError, ResourceRingBuffer};
+
+/// A first-in, first-out queue of bytes over a fixed-size resource.
+///
+/// Internally a [`ResourceRingBuffer`], but [`enqueue`](Self::enqueue)
+/// rejects bytes when full instead of evicting, so no queued data is lost.
+/// Nothing allocates after [`new`](Self::new).
+#[derive(Clone, Debug)]
+pub struct ResourceQueue {
+    ring: ResourceRingBuffer,
+}
+
+impl ResourceQueue {
+    /// Allocates a queue holding up to `capacity` bytes.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`RefinementError::EmptyBuffer`] if `capacity == 0`, or
+    /// [`RefinementError::CapacityOverflow`] if it cannot be allocated.
+    pub fn new(capacity: usize) -> Result<Self, RefinementError> {
+        Ok(Self {
+            ring: ResourceRingBuffer::new(capacity)?,
+        })
+    }
+
+    /// Returns the maximum number of bytes the queue holds.
+    #[inline]
+    pub fn capacity(&self) -> usize {
+        self.ring.capacity()
+    }
+
+    /// Returns the number of queued bytes.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.ring.available()
+    }
+
+    /// Returns `true` if no bytes are queued.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.ring.is_empty()
+    }
+
+    /// Returns how many more bytes can be enqueued.
+    #[inline]
+    pub fn available_space(&self) -> usize {
+        self.ring.capacity() - self.ring.available()
+    }
+
+    /// Appends `b` to the back of the queue.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`RefinementError::Exhausted`] if the queue is full.
+    pub fn enqueue(&mut self, b: u8) -> Result<(), RefinementError> {
+        if self.ring.is_full() {
+            return Err(RefinementError::Exhausted {
+                needed: 1,
+                remaining: 0,
+            });
+        }
+        self.ring.push_byte(b);
+        Ok(())
+    }
+
+    /// Removes and returns the byte at the front of the queue, if any.
+    #[inline]
+    pub fn dequeue(&mut self) -> Option<u8> {
+        self.ring.pop_byte()
+    }
+
+    /// Returns an iterator that dequeues bytes until the queue is empty.
+    ///
+    /// Bytes not consumed before the iterator is dropped stay queued.
+    pub fn drain(&mut self) -> impl Iterator<Item = u8> + '_ {
+        core::iter::from_fn(move || self.dequeue())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_queue_fifo() {
+        let mut queue = ResourceQueue::new(3).unwrap();
+        assert_eq!(queue.dequeue(), None);
+        assert_eq!(queue.available_space(), 3);
+
+        queue.enqueue(1).unwrap();
+        queue.enqueue(2).unwrap();
+        queue.enqueue(3).unwrap();
+        assert_eq!(queue.available_space(), 0);
+        assert_eq!(
+            queue.enqueue(4),
+            Err(RefinementError::Exhausted {
+                needed: 1,
+                remaining: 0
+            })
+        );
+
+        assert_eq!(queue.dequeue(), Some(1));
+        queue.enqueue(4).unwrap();
+        assert!(queue.drain().eq([2, 3, 4]));
+        assert!(queue.is_empty());
+    }
+
+    #[test]
+    fn test_queue_partial_drain() {
+        let mut queue = ResourceQueue::new(4).unwrap();
+        for b in [10, 20, 30] {
+            queue.enqueue(b).unwrap();
+        }
+        assert_eq!(queue.drain().next(), Some(10));
+        assert_eq!(queue.len(), 2);
+        assert_eq!(queue.available_space(), 2);
+    }
+}
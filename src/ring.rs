@@ -0,0 +1,169 @@
+//! Fixed-capacity circular byte buffers.
+
+use crate::{MutRawResource, RawResource, RefinementError};
+
+/// A circular byte buffer over a fixed-size resource.
+///
+/// The backing buffer is allocated once by [`new`](Self::new); pushing and
+/// popping only move the head index and length, so they never allocate. When
+/// the buffer is full, [`push_byte`](Self::push_byte) overwrites the oldest
+/// byte, which suits audio, sensor and receive queues where fresh data
+/// matters more than stale data.
+///
+/// Because an evicting push cannot fail, `push_byte` returns the evicted byte
+/// as an `Option<u8>` rather than a `Result<(), _>`; callers that only care
+/// about success can ignore the return value.
+#[derive(Clone, Debug)]
+pub struct ResourceRingBuffer {
+    buf: MutRawResource,
+    head: usize,
+    len: usize,
+}
+
+impl ResourceRingBuffer {
+    /// Allocates a ring buffer holding up to `capacity` bytes.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`RefinementError::EmptyBuffer`] if `capacity == 0`, or
+    /// [`RefinementError::CapacityOverflow`] if it cannot be allocated.
+    pub fn new(capacity: usize) -> Result<Self, RefinementError> {
+        if capacity == 0 {
+            return Err(RefinementError::EmptyBuffer);
+        }
+        Ok(Self {
+            buf: RawResource::with_capacity(capacity)?.into_mut(),
+            head: 0,
+            len: 0,
+        })
+    }
+
+    /// Returns the maximum number of bytes the buffer holds.
+    #[inline]
+    pub fn capacity(&self) -> usize {
+        self.buf.len()
+    }
+
+    /// Returns the number of bytes waiting to be popped.
+    #[inline]
+    pub fn available(&self) -> usize {
+        self.len
+    }
+
+    /// Returns `true` if no bytes are waiting.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Returns `true` if the next push will evict the oldest byte.
+    #[inline]
+    pub fn is_full(&self) -> bool {
+        self.len == self.capacity()
+    }
+
+    /// Appends `b`, returning the oldest byte if it had to be evicted.
+    pub fn push_byte(&mut self, b: u8) -> Option<u8> {
+        let capacity = self.capacity();
+        let tail = (self.head + self.len) % capacity;
+        if self.len == capacity {
+            let evicted = core::mem::replace(&mut self.buf[tail], b);
+            self.head = (self.head + 1) % capacity;
+            Some(evicted)
+        } else {
+            self.buf[tail] = b;
+            self.len += 1;
+            None
+        }
+    }
+
+    /// Removes and returns the oldest byte, if any.
+    pub fn pop_byte(&mut self) -> Option<u8> {
+        if self.len == 0 {
+            return None;
+        }
+        let b = self.buf[self.head];
+        self.head = (self.head + 1) % self.capacity();
+        self.len -= 1;
+        Some(b)
+    }
+
+    /// Discards every waiting byte without touching the allocation.
+    pub fn clear(&mut self) {
+        self.head = 0;
+        self.len = 0;
+    }
+
+    /// Removes every waiting byte and returns them, oldest first, as a new
+    /// resource.
+    pub fn drain_into(&mut self) -> RawResource {
+        let end = self.head + self.len;
+        let capacity = self.capacity();
+        let drained = if end <= capacity {
+            RawResource::from(&self.buf[self.head..end])
+        } else {
+            let (wrapped, first) = self.buf.split_at(self.head);
+            first
+                .iter()
+                .chain(&wrapped[..end - capacity])
+                .copied()
+                .collect()
+        };
+        self.clear();
+        drained
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_ring_push_pop() {
+        let mut ring = ResourceRingBuffer::new(3).unwrap();
+        assert!(ring.is_empty());
+        assert_eq!(ring.pop_byte(), None);
+
+        assert_eq!(ring.push_byte(1), None);
+        assert_eq!(ring.push_byte(2), None);
+        assert_eq!(ring.pop_byte(), Some(1));
+        assert_eq!(ring.push_byte(3), None);
+        assert_eq!(ring.push_byte(4), None);
+        assert!(ring.is_full());
+        assert_eq!(ring.available(), 3);
+
+        assert_eq!(ring.pop_byte(), Some(2));
+        assert_eq!(ring.pop_byte(), Some(3));
+        assert_eq!(ring.pop_byte(), Some(4));
+        assert_eq!(ring.pop_byte(), None);
+    }
+
+    #[test]
+    fn test_ring_evicts_oldest() {
+        let mut ring = ResourceRingBuffer::new(3).unwrap();
+        let ptr = ring.buf.as_ptr();
+        for b in 1..=3 {
+            ring.push_byte(b);
+        }
+        assert_eq!(ring.push_byte(4), Some(1));
+        assert_eq!(ring.push_byte(5), Some(2));
+        assert_eq!(ring.available(), 3);
+        assert_eq!(ring.drain_into().as_slice(), &[3, 4, 5]);
+        assert!(ring.is_empty());
+        assert_eq!(ring.buf.as_ptr(), ptr);
+    }
+
+    #[test]
+    fn test_ring_drain_contiguous_and_zero_capacity() {
+        let mut ring = ResourceRingBuffer::new(4).unwrap();
+        ring.push_byte(7);
+        ring.push_byte(8);
+        assert_eq!(ring.drain_into().as_slice(), &[7, 8]);
+        assert!(ring.drain_into().is_empty());
+
+        assert_eq!(
+            ResourceRingBuffer::new(0).unwrap_err(),
+            RefinementError::EmptyBuffer
+        );
+    }
+}
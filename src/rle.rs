@@ -0,0 +1,181 @@
+//! Escape-based run-length encoding.
+
+use alloc::vec::Vec;
+
+use crate::{RawResource, RefinementError, ResourceDecoder, ResourceEncoder};
+
+/// The escape byte used by `Default`, matching the BinHex 4.0 RLE marker.
+const DEFAULT_ESCAPE: u8 = 0x90;
+
+/// Runs shorter than this are emitted as literals.
+const MIN_RUN: usize = 4;
+
+/// Run-length encoder for low-entropy data such as embedded telemetry.
+///
+/// The encoded stream is a sequence of literal bytes and runs. A run is the
+/// three bytes `escape, count, value` and expands to `count` (1..=255)
+/// copies of `value`; every other byte stands for itself. Runs shorter than
+/// four bytes are left literal, since encoding them would not save space,
+/// except that the escape byte itself is always encoded as a run. Runs longer
+/// than 255 bytes are split. Data without repeats therefore grows only by two
+/// bytes per occurrence of the escape byte.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct RleEncoder {
+    escape: u8,
+}
+
+impl RleEncoder {
+    /// Creates an encoder that introduces runs with `escape`.
+    ///
+    /// Pick a byte that is rare in the data; each literal occurrence costs
+    /// three output bytes.
+    pub const fn new(escape: u8) -> Self {
+        Self { escape }
+    }
+}
+
+impl Default for RleEncoder {
+    fn default() -> Self {
+        Self::new(DEFAULT_ESCAPE)
+    }
+}
+
+impl ResourceEncoder for RleEncoder {
+    fn encode(&self, input: &RawResource) -> Result<RawResource, RefinementError> {
+        let mut out = Vec::with_capacity(input.len());
+        for run in input.as_slice().chunk_by(|a, b| a == b) {
+            let value = run[0];
+            if run.len() < MIN_RUN && value != self.escape {
+                out.extend_from_slice(run);
+                continue;
+            }
+            for part in run.chunks(usize::from(u8::MAX)) {
+                // `chunks(255)` bounds every part, so the cast is lossless.
+                out.extend([self.escape, part.len() as u8, value]);
+            }
+        }
+        RawResource::refine(out)
+    }
+}
+
+/// Run-length decoder for the format described on [`RleEncoder`].
+///
+/// Must be configured with the same escape byte as the encoder.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct RleDecoder {
+    escape: u8,
+}
+
+impl RleDecoder {
+    /// Creates a decoder that treats `escape` as the start of a run.
+    pub const fn new(escape: u8) -> Self {
+        Self { escape }
+    }
+}
+
+impl Default for RleDecoder {
+    fn default() -> Self {
+        Self::new(DEFAULT_ESCAPE)
+    }
+}
+
+impl ResourceDecoder for RleDecoder {
+    /// # Errors
+    ///
+    /// Returns [`RefinementError::MalformedEncoding`] if a run is truncated
+    /// or has a count of zero.
+    fn decode(&self, input: &RawResource) -> Result<RawResource, RefinementError> {
+        let bytes = input.as_slice();
+        let mut out = Vec::with_capacity(bytes.len());
+        let mut offset = 0;
+        while let Some(&byte) = bytes.get(offset) {
+            if byte != self.escape {
+                out.push(byte);
+                offset += 1;
+                continue;
+            }
+            match bytes.get(offset + 1..offset + 3) {
+                Some(&[count, value]) if count > 0 => {
+                    out.resize(out.len() + usize::from(count), value);
+                    offset += 3;
+                }
+                _ => return Err(RefinementError::MalformedEncoding { offset }),
+            }
+        }
+        RawResource::refine(out)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn round_trip(input: &[u8], escape: u8) -> RawResource {
+        let input = RawResource::from(input);
+        let encoded = RleEncoder::new(escape).encode(&input).unwrap();
+        let decoded = RleDecoder::new(escape).decode(&encoded).unwrap();
+        assert_eq!(decoded, input);
+        encoded
+    }
+
+    #[test]
+    fn test_rle_runs() {
+        let encoded = round_trip(b"aaaaaab", 0x90);
+        assert_eq!(encoded.as_slice(), &[0x90, 6, b'a', b'b']);
+
+        let mut long = alloc::vec![7u8; 600];
+        long.push(8);
+        let encoded = round_trip(&long, 0x90);
+        assert_eq!(
+            encoded.as_slice(),
+            &[0x90, 255, 7, 0x90, 255, 7, 0x90, 90, 7, 8]
+        );
+    }
+
+    #[test]
+    fn test_rle_unique_and_escape_bytes() {
+        let unique: Vec<u8> = (0..=255).collect();
+        let encoded = round_trip(&unique, 0x90);
+        assert_eq!(encoded.len(), 256 + 2);
+
+        let encoded = round_trip(&[0x90, 1, 0x90, 0x90], 0x90);
+        assert_eq!(encoded.as_slice(), &[0x90, 1, 0x90, 1, 0x90, 2, 0x90]);
+        assert!(round_trip(&[], 0x90).is_empty());
+    }
+
+    #[test]
+    fn test_rle_fuzz_round_trip() {
+        // xorshift32, with a small alphabet so that runs actually occur.
+        let mut state = 0x2545_F491u32;
+        let mut next = move || {
+            state ^= state << 13;
+            state ^= state >> 17;
+            state ^= state << 5;
+            state
+        };
+        for _ in 0..200 {
+            let len = (next() % 1024) as usize;
+            let alphabet = (next() % 4 + 1) as u8;
+            let escape = (next() % u32::from(alphabet)) as u8;
+            let input: Vec<u8> = (0..len)
+                .map(|_| (next() % u32::from(alphabet)) as u8)
+                .collect();
+            round_trip(&input, escape);
+        }
+    }
+
+    #[test]
+    fn test_rle_malformed() {
+        let decoder = RleDecoder::default();
+        for (input, offset) in [
+            (&[b'x', 0x90][..], 1),
+            (&[0x90, 3][..], 0),
+            (&[0x90, 0, b'a'][..], 0),
+        ] {
+            assert_eq!(
+                decoder.decode(&RawResource::from(input)),
+                Err(RefinementError::MalformedEncoding { offset })
+            );
+        }
+    }
+}
@@ -0,0 +1,108 @@
+//! Byte and substring search shared by resources and views.
+//!
+//! With the `memchr` feature these delegate to the SIMD-accelerated `memchr`
+//! crate; otherwise they fall back to straightforward scans.
+
+/// Returns the offset of the first `needle` byte in `haystack`.
+#[inline]
+pub(crate) fn find_byte(haystack: &[u8], needle: u8) -> Option<usize> {
+    #[cfg(feature = "memchr")]
+    {
+        memchr::memchr(needle, haystack)
+    }
+    #[cfg(not(feature = "memchr"))]
+    {
+        haystack.iter().position(|&b| b == needle)
+    }
+}
+
+/// Returns the offset of the first occurrence of `needle` in `haystack`.
+///
+/// An empty needle matches at offset 0.
+#[inline]
+pub(crate) fn find_slice(haystack: &[u8], needle: &[u8]) -> Option<usize> {
+    #[cfg(feature = "memchr")]
+    {
+        memchr::memmem::find(haystack, needle)
+    }
+    #[cfg(not(feature = "memchr"))]
+    {
+        if needle.is_empty() {
+            return Some(0);
+        }
+        haystack
+            .windows(needle.len())
+            .position(|window| window == needle)
+    }
+}
+
+/// Returns the offset of the last `needle` byte in `haystack`.
+#[inline]
+pub(crate) fn rfind_byte(haystack: &[u8], needle: u8) -> Option<usize> {
+    #[cfg(feature = "memchr")]
+    {
+        memchr::memrchr(needle, haystack)
+    }
+    #[cfg(not(feature = "memchr"))]
+    {
+        haystack.iter().rposition(|&b| b == needle)
+    }
+}
+
+/// Returns the offset of the last occurrence of `needle` in `haystack`.
+///
+/// An empty needle matches at `haystack.len()`.
+#[inline]
+pub(crate) fn rfind_slice(haystack: &[u8], needle: &[u8]) -> Option<usize> {
+    #[cfg(feature = "memchr")]
+    {
+        memchr::memmem::rfind(haystack, needle)
+    }
+    #[cfg(not(feature = "memchr"))]
+    {
+        if needle.is_empty() {
+            return Some(haystack.len());
+        }
+        haystack
+            .windows(needle.len())
+            .rposition(|window| window == needle)
+    }
+}
+
+/// Returns the number of `needle` bytes in `haystack`.
+#[inline]
+pub(crate) fn count_byte(haystack: &[u8], needle: u8) -> usize {
+    #[cfg(feature = "memchr")]
+    {
+        memchr::memchr_iter(needle, haystack).count()
+    }
+    #[cfg(not(feature = "memchr"))]
+    {
+        haystack.iter().filter(|&&b| b == needle).count()
+    }
+}
+
+/// Returns the number of non-overlapping occurrences of `needle` in
+/// `haystack`, scanning left to right.
+///
+/// An empty needle matches at every offset, `haystack.len() + 1` times.
+#[inline]
+pub(crate) fn count_slice(haystack: &[u8], needle: &[u8]) -> usize {
+    #[cfg(feature = "memchr")]
+    {
+        memchr::memmem::find_iter(haystack, needle).count()
+    }
+    #[cfg(not(feature = "memchr"))]
+    {
+        if needle.is_empty() {
+            return haystack.len() + 1;
+        }
+        let mut count = 0;
+        let mut rest = haystack;
+        while let Some(offset) = find_slice(rest, needle) {
+            count += 1;
+            rest = &rest[offset + needle.len()..];
+        }
+        count
+    }
+}
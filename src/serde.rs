@@ -0,0 +1,73 @@
+//! `serde` support for [`RawResource`].
+//!
+//! Human-readable formats (e.g. JSON) use a lowercase hex string; binary
+//! formats (e.g. bincode, MessagePack) use a native byte sequence.
+
+use alloc::vec::Vec;
+use core::fmt;
+
+use serde::de::{self, Deserialize, Deserializer, SeqAccess, Visitor};
+use serde::ser::{Serialize, Serializer};
+
+use crate::RawResource;
+use crate::hex::HexDisplay;
+
+impl Serialize for RawResource {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        if serializer.is_human_readable() {
+            serializer.collect_str(&HexDisplay(self.as_slice()))
+        } else {
+            serializer.serialize_bytes(self.as_slice())
+        }
+    }
+}
+
+struct HexVisitor;
+
+impl Visitor<'_> for HexVisitor {
+    type Value = RawResource;
+
+    fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("a hex-encoded byte string")
+    }
+
+    fn visit_str<E: de::Error>(self, v: &str) -> Result<RawResource, E> {
+        RawResource::refine_from_hex(v).map_err(|_| E::invalid_value(de::Unexpected::Str(v), &self))
+    }
+}
+
+struct BytesVisitor;
+
+impl<'de> Visitor<'de> for BytesVisitor {
+    type Value = RawResource;
+
+    fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("a byte sequence")
+    }
+
+    fn visit_bytes<E: de::Error>(self, v: &[u8]) -> Result<RawResource, E> {
+        Ok(RawResource::from(v))
+    }
+
+    fn visit_byte_buf<E: de::Error>(self, v: Vec<u8>) -> Result<RawResource, E> {
+        RawResource::refine(v).map_err(E::custom)
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<RawResource, A::Error> {
+        let mut data = Vec::with_capacity(seq.size_hint().unwrap_or(0).min(4096));
+        while let Some(byte) = seq.next_element()? {
+            data.push(byte);
+        }
+        RawResource::refine(data).map_err(de::Error::custom)
+    }
+}
+
+impl<'de> Deserialize<'de> for RawResource {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        if deserializer.is_human_readable() {
+            deserializer.deserialize_str(HexVisitor)
+        } else {
+            deserializer.deserialize_byte_buf(BytesVisitor)
+        }
+    }
+}
@@ -0,0 +1,252 @@
+//! Fixed-size slot allocation out of one contiguous buffer.
+
+use alloc::vec::Vec;
+use core::sync::atomic::{AtomicU64, Ordering};
+
+use crate::{MutRawResource, RawResource, RefinementError};
+
+const WORD_BITS: usize = u64::BITS as usize;
+
+/// A handle to one slot of a [`ResourceSlabAllocator`].
+///
+/// Not `Clone`, so a slot cannot be released twice. Access the bytes through
+/// [`ResourceSlabAllocator::slot`] and [`ResourceSlabAllocator::slot_mut`].
+///
+/// Each slot records the id of the allocator it came from, so passing it to
+/// any other allocator panics instead of aliasing that allocator's slot.
+#[derive(Debug, PartialEq, Eq, Hash)]
+pub struct ResourceSlot {
+    index: usize,
+    owner: u64,
+}
+
+impl ResourceSlot {
+    /// Returns the slot's position within the slab.
+    #[inline]
+    pub fn index(&self) -> usize {
+        self.index
+    }
+}
+
+/// Hands out equal-size slots from a single allocation.
+///
+/// Suits workloads where every buffer has the same size, such as Ethernet
+/// frames or 512-byte disk sectors: the slab is allocated once, so acquiring
+/// and releasing slots never touches the heap or fragments it. Free slots are
+/// tracked in a bitset, and acquisition scans it a 64-slot word at a time.
+///
+/// Only available on targets with 64-bit atomics, which hand out the
+/// process-unique allocator ids that slots are checked against.
+#[derive(Debug)]
+pub struct ResourceSlabAllocator {
+    id: u64,
+    slab: MutRawResource,
+    slot_size: usize,
+    slot_count: usize,
+    /// One bit per slot; set bits are free.
+    free: Vec<u64>,
+    in_use: usize,
+}
+
+impl ResourceSlabAllocator {
+    /// Allocates `slot_count` zeroed slots of `slot_size` bytes each.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`RefinementError::EmptyBuffer`] if either argument is zero,
+    /// or [`RefinementError::CapacityOverflow`] if the slab is too large.
+    pub fn new(slot_size: usize, slot_count: usize) -> Result<Self, RefinementError> {
+        if slot_size == 0 || slot_count == 0 {
+            return Err(RefinementError::EmptyBuffer);
+        }
+        let total = slot_size
+            .checked_mul(slot_count)
+            .ok_or(RefinementError::CapacityOverflow)?;
+        let mut free = alloc::vec![u64::MAX; slot_count.div_ceil(WORD_BITS)];
+        let tail = slot_count % WORD_BITS;
+        if tail != 0 {
+            free[slot_count / WORD_BITS] = (1 << tail) - 1;
+        }
+        static NEXT_ID: AtomicU64 = AtomicU64::new(0);
+        Ok(Self {
+            id: NEXT_ID.fetch_add(1, Ordering::Relaxed),
+            slab: RawResource::with_capacity(total)?.into_mut(),
+            slot_size,
+            slot_count,
+            free,
+            in_use: 0,
+        })
+    }
+
+    /// Returns the size of each slot in bytes.
+    #[inline]
+    pub fn slot_size(&self) -> usize {
+        self.slot_size
+    }
+
+    /// Returns the total number of slots.
+    #[inline]
+    pub fn slot_count(&self) -> usize {
+        self.slot_count
+    }
+
+    /// Returns the number of slots currently acquired.
+    #[inline]
+    pub fn in_use(&self) -> usize {
+        self.in_use
+    }
+
+    /// Acquires a free slot, or returns `None` if all are in use.
+    ///
+    /// The slot keeps whatever bytes its previous user left in it.
+    pub fn acquire(&mut self) -> Option<ResourceSlot> {
+        let (word_index, word) = self.free.iter_mut().enumerate().find(|(_, w)| **w != 0)?;
+        let bit = word.trailing_zeros() as usize;
+        *word &= !(1 << bit);
+        self.in_use += 1;
+        Some(ResourceSlot {
+            index: word_index * WORD_BITS + bit,
+            owner: self.id,
+        })
+    }
+
+    /// Returns `slot` to the free set.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `slot` did not come from this slab.
+    pub fn release(&mut self, slot: ResourceSlot) {
+        let (word, mask) = self.locate(&slot);
+        assert!(
+            self.free[word] & mask == 0,
+            "slot {} is not in use",
+            slot.index
+        );
+        self.free[word] |= mask;
+        self.in_use -= 1;
+    }
+
+    /// Returns the bytes of `slot`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `slot` did not come from this slab.
+    pub fn slot(&self, slot: &ResourceSlot) -> &[u8] {
+        self.locate(slot);
+        let start = slot.index * self.slot_size;
+        &self.slab[start..start + self.slot_size]
+    }
+
+    /// Returns the bytes of `slot` mutably.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `slot` did not come from this slab.
+    pub fn slot_mut(&mut self, slot: &ResourceSlot) -> &mut [u8] {
+        self.locate(slot);
+        let start = slot.index * self.slot_size;
+        &mut self.slab[start..start + self.slot_size]
+    }
+
+    fn locate(&self, slot: &ResourceSlot) -> (usize, u64) {
+        assert!(
+            slot.index < self.slot_count,
+            "slot {} out of bounds for {} slots",
+            slot.index,
+            self.slot_count
+        );
+        assert!(
+            slot.owner == self.id,
+            "slot {} belongs to a different slab",
+            slot.index
+        );
+        (slot.index / WORD_BITS, 1 << (slot.index % WORD_BITS))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_slab_acquire_release() {
+        let mut slab = ResourceSlabAllocator::new(512, 3).unwrap();
+        let a = slab.acquire().unwrap();
+        let b = slab.acquire().unwrap();
+        let c = slab.acquire().unwrap();
+        assert_eq!([a.index(), b.index(), c.index()], [0, 1, 2]);
+        assert!(slab.acquire().is_none());
+        assert_eq!(slab.in_use(), 3);
+
+        slab.slot_mut(&b).fill(0xBB);
+        assert!(slab.slot(&a).iter().all(|&x| x == 0));
+        assert!(slab.slot(&b).iter().all(|&x| x == 0xBB));
+        assert_eq!(slab.slot(&c).len(), 512);
+
+        slab.release(b);
+        let reused = slab.acquire().unwrap();
+        assert_eq!(reused.index(), 1);
+        assert_eq!(slab.in_use(), 3);
+    }
+
+    #[test]
+    fn test_slab_spans_bitset_words() {
+        let mut slab = ResourceSlabAllocator::new(1, 130).unwrap();
+        let slots: Vec<_> = core::iter::from_fn(|| slab.acquire()).collect();
+        assert_eq!(slots.len(), 130);
+        assert_eq!(slots[129].index(), 129);
+        for slot in slots {
+            slab.release(slot);
+        }
+        assert_eq!(slab.in_use(), 0);
+    }
+
+    #[test]
+    fn test_slab_rejects_zero_sizes() {
+        assert_eq!(
+            ResourceSlabAllocator::new(0, 4).unwrap_err(),
+            RefinementError::EmptyBuffer
+        );
+        assert_eq!(
+            ResourceSlabAllocator::new(4, 0).unwrap_err(),
+            RefinementError::EmptyBuffer
+        );
+        assert_eq!(
+            ResourceSlabAllocator::new(usize::MAX, 2).unwrap_err(),
+            RefinementError::CapacityOverflow
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "out of bounds")]
+    fn test_slab_rejects_foreign_slot() {
+        let mut small = ResourceSlabAllocator::new(8, 1).unwrap();
+        let mut large = ResourceSlabAllocator::new(8, 4).unwrap();
+        let foreign = core::iter::from_fn(|| large.acquire()).last().unwrap();
+        assert_eq!(foreign.index(), 3);
+        small.release(foreign);
+    }
+
+    #[test]
+    #[should_panic(expected = "different slab")]
+    fn test_slab_rejects_foreign_slot_in_range() {
+        let mut first = ResourceSlabAllocator::new(8, 2).unwrap();
+        let mut second = ResourceSlabAllocator::new(8, 2).unwrap();
+        let foreign = second.acquire().unwrap();
+        let _held = first.acquire().unwrap();
+        assert_eq!(foreign.index(), 0);
+        first.release(foreign);
+    }
+
+    #[test]
+    #[should_panic(expected = "different slab")]
+    fn test_slab_rejects_slot_from_dropped_slab() {
+        let mut old = ResourceSlabAllocator::new(8, 2).unwrap();
+        let stale = old.acquire().unwrap();
+        drop(old);
+        let mut new = ResourceSlabAllocator::new(8, 2).unwrap();
+        let _held = new.acquire().unwrap();
+        assert_eq!(stale.index(), 0);
+        new.release(stale);
+    }
+}
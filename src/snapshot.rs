@@ -0,0 +1,75 @@
+//! Point-in-time copies of a resource for debugging.
+
+#[cfg(target_has_atomic = "64")]
+use core::sync::atomic::{AtomicU64, Ordering};
+
+use crate::RawResource;
+
+/// A private copy of a resource's bytes, stamped with a logical timestamp.
+///
+/// Timestamps come from a process-wide monotonic counter rather than a clock,
+/// so they order snapshots without needing `std`. Comparing snapshots taken
+/// at different stages of a state machine shows where a buffer changed.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct ResourceSnapshot {
+    data: RawResource,
+    timestamp: u64,
+}
+
+impl ResourceSnapshot {
+    /// Returns the captured bytes.
+    #[inline]
+    pub fn data(&self) -> &RawResource {
+        &self.data
+    }
+
+    /// Returns the logical time of capture; later snapshots have larger
+    /// timestamps.
+    #[inline]
+    pub fn timestamp(&self) -> u64 {
+        self.timestamp
+    }
+
+    /// Returns `true` if `resource` currently holds the captured bytes.
+    #[inline]
+    pub fn matches(&self, resource: &RawResource) -> bool {
+        self.data == *resource
+    }
+}
+
+#[cfg(target_has_atomic = "64")]
+impl RawResource {
+    /// Captures a copy of the current bytes.
+    ///
+    /// Always deep-copies, so the snapshot stays valid and unshared however
+    /// the resource is later mutated. Only available on targets with 64-bit
+    /// atomics.
+    pub fn snapshot(&self) -> ResourceSnapshot {
+        static CLOCK: AtomicU64 = AtomicU64::new(0);
+        ResourceSnapshot {
+            data: self.deep_clone(),
+            timestamp: CLOCK.fetch_add(1, Ordering::Relaxed),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_snapshot_matches_until_mutated() {
+        let mut resource = RawResource::from(&[1, 2, 3][..]);
+        let before = resource.snapshot();
+        assert!(before.matches(&resource));
+        assert_ne!(before.data().as_ptr(), resource.as_ptr());
+
+        resource.fill(0);
+        assert!(!before.matches(&resource));
+        assert_eq!(before.data().as_slice(), &[1, 2, 3]);
+
+        let after = resource.snapshot();
+        assert!(after.matches(&resource));
+        assert!(after.timestamp() > before.timestamp());
+    }
+}
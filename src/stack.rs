@@ -0,0 +1,137 @@
+//! Fixed-capacity LIFO byte stacks.
+
+use crate::{MutRawResource, RawResource, RefinementError};
+
+/// A last-in, first-out stack of bytes over a fixed-size resource.
+///
+/// The backing buffer is allocated once by [`new`](Self::new); a length
+/// field acts as the stack pointer, so pushing and popping never allocate.
+#[derive(Clone, Debug)]
+pub struct ResourceStack {
+    buf: MutRawResource,
+    len: usize,
+}
+
+impl ResourceStack {
+    /// Allocates a stack holding up to `capacity` bytes.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`RefinementError::EmptyBuffer`] if `capacity == 0`, or
+    /// [`RefinementError::CapacityOverflow`] if it cannot be allocated.
+    pub fn new(capacity: usize) -> Result<Self, RefinementError> {
+        if capacity == 0 {
+            return Err(RefinementError::EmptyBuffer);
+        }
+        Ok(Self {
+            buf: RawResource::with_capacity(capacity)?.into_mut(),
+            len: 0,
+        })
+    }
+
+    /// Returns the maximum number of bytes the stack holds.
+    #[inline]
+    pub fn capacity(&self) -> usize {
+        self.buf.len()
+    }
+
+    /// Returns the number of bytes on the stack.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns `true` if the stack holds no bytes.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Returns `true` if no more bytes can be pushed.
+    #[inline]
+    pub fn is_full(&self) -> bool {
+        self.len == self.capacity()
+    }
+
+    /// Pushes `b` onto the top of the stack.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`RefinementError::Exhausted`] if the stack is full.
+    pub fn push(&mut self, b: u8) -> Result<(), RefinementError> {
+        if self.is_full() {
+            return Err(RefinementError::Exhausted {
+                needed: 1,
+                remaining: 0,
+            });
+        }
+        self.buf[self.len] = b;
+        self.len += 1;
+        Ok(())
+    }
+
+    /// Removes and returns the top byte, if any.
+    pub fn pop(&mut self) -> Option<u8> {
+        self.len = self.len.checked_sub(1)?;
+        Some(self.buf[self.len])
+    }
+
+    /// Returns the top byte without removing it.
+    #[inline]
+    pub fn peek(&self) -> Option<u8> {
+        self.len.checked_sub(1).map(|top| self.buf[top])
+    }
+
+    /// Returns the stacked bytes, bottom first.
+    #[inline]
+    pub fn as_slice(&self) -> &[u8] {
+        &self.buf[..self.len]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_stack_lifo() {
+        let mut stack = ResourceStack::new(3).unwrap();
+        assert_eq!(stack.pop(), None);
+        assert_eq!(stack.peek(), None);
+
+        stack.push(1).unwrap();
+        stack.push(2).unwrap();
+        assert_eq!(stack.peek(), Some(2));
+        stack.push(3).unwrap();
+        assert!(stack.is_full());
+        assert_eq!(stack.as_slice(), &[1, 2, 3]);
+
+        assert_eq!(stack.pop(), Some(3));
+        assert_eq!(stack.pop(), Some(2));
+        assert_eq!(stack.len(), 1);
+        assert_eq!(stack.pop(), Some(1));
+        assert!(stack.is_empty());
+    }
+
+    #[test]
+    fn test_stack_full() {
+        let mut stack = ResourceStack::new(1).unwrap();
+        stack.push(9).unwrap();
+        assert_eq!(
+            stack.push(10),
+            Err(RefinementError::Exhausted {
+                needed: 1,
+                remaining: 0
+            })
+        );
+        assert_eq!(stack.peek(), Some(9));
+    }
+
+    #[test]
+    fn test_stack_rejects_zero_capacity() {
+        assert_eq!(
+            ResourceStack::new(0).unwrap_err(),
+            RefinementError::EmptyBuffer
+        );
+    }
+}
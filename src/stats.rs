@@ -0,0 +1,58 @@
+//! Memory footprint introspection.
+
+use crate::MutRawResource;
+
+/// A snapshot of where and how a buffer is allocated.
+///
+/// Purely informational: the values are read once and not kept in sync with
+/// the buffer.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct MemoryStats {
+    /// Address of the first byte.
+    pub ptr_addr: usize,
+    /// Number of bytes in use.
+    pub len: usize,
+    /// Size of the allocation in bytes.
+    pub cap: usize,
+    /// `cap - len`: bytes allocated but not in use.
+    pub wasted_bytes: usize,
+    /// Largest power of two dividing `ptr_addr` (its lowest set bit), or 0
+    /// for a null address.
+    pub alignment: usize,
+}
+
+impl MutRawResource {
+    /// Reports the address, length and capacity of the allocation.
+    ///
+    /// `RawResource` cannot report capacity because `bytes::Bytes` does not
+    /// expose it; take stats of a unique resource after a zero-copy
+    /// [`RawResource::into_mut`](crate::RawResource::into_mut).
+    pub fn memory_stats(&self) -> MemoryStats {
+        let ptr_addr = self.as_ptr().addr();
+        MemoryStats {
+            ptr_addr,
+            len: self.len(),
+            cap: self.capacity(),
+            wasted_bytes: self.excess_capacity(),
+            alignment: ptr_addr & ptr_addr.wrapping_neg(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::RawResource;
+
+    #[test]
+    fn test_memory_stats() {
+        let mut resource = RawResource::with_capacity(64).unwrap();
+        resource.truncate(40).unwrap();
+        let stats = resource.into_mut().memory_stats();
+        assert_eq!(stats.len, 40);
+        assert_eq!(stats.cap, 64);
+        assert_eq!(stats.wasted_bytes, 24);
+        assert!(stats.alignment.is_power_of_two());
+        assert_eq!(stats.ptr_addr % stats.alignment, 0);
+        assert_ne!((stats.ptr_addr / stats.alignment) % 2, 0);
+    }
+}
@@ -0,0 +1,23 @@
+//! `proptest` strategies for generating [`RawResource`] values.
+
+use proptest::collection::vec;
+use proptest::prelude::*;
+
+use crate::RawResource;
+
+/// Largest length produced by [`arb_resource`].
+const MAX_LEN: usize = 65536;
+
+/// Generates resources of 1 to 65536 arbitrary bytes.
+pub fn arb_resource() -> impl Strategy<Value = RawResource> {
+    vec(any::<u8>(), 1..=MAX_LEN).prop_map(into_resource)
+}
+
+/// Generates resources of exactly `len` arbitrary bytes.
+pub fn arb_resource_of_len(len: usize) -> impl Strategy<Value = RawResource> {
+    vec(any::<u8>(), len).prop_map(into_resource)
+}
+
+fn into_resource(data: alloc::vec::Vec<u8>) -> RawResource {
+    RawResource::refine(data).expect("refine is infallible")
+}
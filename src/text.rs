@@ -0,0 +1,152 @@
+//! Text and C-string views over resources carrying textual protocols.
+
+use core::ffi::{CStr, FromBytesUntilNulError};
+use core::str::Utf8Error;
+
+use crate::{RawResource, RefinementError, ResourceView};
+
+impl RawResource {
+    /// Returns the bytes as a `&str` if they are valid UTF-8.
+    ///
+    /// Zero-copy: the string borrows from the resource and cannot outlive it.
+    ///
+    /// # Errors
+    ///
+    /// Returns the [`Utf8Error`] from `core::str::from_utf8`, which reports
+    /// how many leading bytes were valid.
+    #[inline]
+    pub fn try_as_str(&self) -> Result<&str, Utf8Error> {
+        core::str::from_utf8(self.as_slice())
+    }
+
+    /// Returns `true` if the bytes are valid UTF-8.
+    #[inline]
+    pub fn is_valid_utf8(&self) -> bool {
+        self.try_as_str().is_ok()
+    }
+
+    /// Returns the bytes up to and including the first NUL as a `&CStr`.
+    ///
+    /// Zero-copy; any bytes after the first NUL are ignored.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`FromBytesUntilNulError`] if the resource contains no NUL.
+    #[inline]
+    pub fn try_as_c_str(&self) -> Result<&CStr, FromBytesUntilNulError> {
+        CStr::from_bytes_until_nul(self.as_slice())
+    }
+
+    /// Iterates over lines as zero-copy views, splitting on `\n` and
+    /// stripping a `\r` that precedes it.
+    ///
+    /// Matches `str::lines`: a trailing line ending does not produce an
+    /// extra empty line, a buffer without line endings is one line, and an
+    /// empty buffer has none.
+    pub fn lines(&self) -> impl DoubleEndedIterator<Item = ResourceView<'_>> {
+        self.as_slice()
+            .split_inclusive(|&b| b == b'\n')
+            .map(|line| {
+                let line = match line.strip_suffix(b"\n") {
+                    Some(line) => line.strip_suffix(b"\r").unwrap_or(line),
+                    None => line,
+                };
+                ResourceView::new(line)
+            })
+    }
+
+    /// Iterates over the NUL-terminated strings packed into the resource, as
+    /// in the Linux `environ` and `/proc/<pid>/cmdline` formats.
+    ///
+    /// Yields each string without its terminator and stops at the first
+    /// empty string (a double NUL) or at the end of the buffer; a final
+    /// string missing its terminator is still yielded.
+    pub fn null_terminated_iter(&self) -> impl Iterator<Item = &[u8]> {
+        self.as_slice()
+            .split(|&b| b == 0)
+            .take_while(|segment| !segment.is_empty())
+    }
+
+    /// Copies `cstr`, including its NUL terminator, into a new resource.
+    ///
+    /// # Errors
+    ///
+    /// Currently infallible, mirroring [`refine`](Self::refine).
+    pub fn refine_from_c_str(cstr: &CStr) -> Result<Self, RefinementError> {
+        Ok(Self::from(cstr.to_bytes_with_nul()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_try_as_str() {
+        let header = RawResource::from("Host: example.com");
+        assert_eq!(header.try_as_str(), Ok("Host: example.com"));
+        assert_eq!(header.try_as_str().unwrap().as_ptr(), header.as_ptr());
+        assert!(header.is_valid_utf8());
+
+        let broken = RawResource::from(&[b'o', b'k', 0xC3][..]);
+        assert_eq!(broken.try_as_str().unwrap_err().valid_up_to(), 2);
+        assert!(!broken.is_valid_utf8());
+        assert_eq!(RawResource::from("").try_as_str(), Ok(""));
+    }
+
+    fn assert_lines(text: &str, expected: &[&[u8]]) {
+        let resource = RawResource::from(text);
+        let lines: alloc::vec::Vec<&[u8]> = resource.lines().map(|line| line.as_slice()).collect();
+        assert_eq!(lines, expected, "lines of {text:?}");
+    }
+
+    #[test]
+    fn test_lines_endings() {
+        assert_lines("a\r\nb\r\n", &[b"a", b"b"]);
+        assert_lines("a\nb\nc", &[b"a", b"b", b"c"]);
+        assert_lines("a\r\nb\nc\r\n", &[b"a", b"b", b"c"]);
+        assert_lines("no newline", &[b"no newline"]);
+        assert_lines("trailing\n", &[b"trailing"]);
+        assert_lines("\n\n", &[b"", b""]);
+        assert_lines("lone\r", &[b"lone\r"]);
+        assert_lines("", &[]);
+    }
+
+    #[test]
+    fn test_lines_are_zero_copy() {
+        let resource = RawResource::from("GET / HTTP/1.1\r\nHost: x\r\n\r\n");
+        let mut lines = resource.lines();
+        let request_line = lines.next().unwrap();
+        assert_eq!(request_line.as_ptr(), resource.as_ptr());
+        assert_eq!(request_line.as_slice(), b"GET / HTTP/1.1");
+        assert_eq!(lines.next().unwrap().as_slice(), b"Host: x");
+        assert!(lines.next().unwrap().is_empty());
+        assert!(lines.next().is_none());
+    }
+
+    #[test]
+    fn test_null_terminated_iter() {
+        let environ = RawResource::from(&b"HOME=/root\0PATH=/bin\0TERM=xterm\0\0ignored\0"[..]);
+        let vars: alloc::vec::Vec<&[u8]> = environ.null_terminated_iter().collect();
+        assert_eq!(vars, [&b"HOME=/root"[..], b"PATH=/bin", b"TERM=xterm"]);
+
+        let unterminated = RawResource::from(&b"a\0b"[..]);
+        assert!(unterminated.null_terminated_iter().eq([&b"a"[..], b"b"]));
+        assert_eq!(RawResource::from("").null_terminated_iter().count(), 0);
+        assert_eq!(
+            RawResource::from(&[0u8][..]).null_terminated_iter().count(),
+            0
+        );
+    }
+
+    #[test]
+    fn test_c_str_round_trip() {
+        let resource = RawResource::refine_from_c_str(c"eth0").unwrap();
+        assert_eq!(resource.as_slice(), b"eth0\0");
+        assert_eq!(resource.try_as_c_str(), Ok(c"eth0"));
+
+        let padded = RawResource::from(&b"lo\0\0\0garbage"[..]);
+        assert_eq!(padded.try_as_c_str(), Ok(c"lo"));
+        assert!(RawResource::from("no terminator").try_as_c_str().is_err());
+    }
+}
@@ -0,0 +1,104 @@
+//! One-pass byte-level transforms.
+
+use crate::{RawResource, RefinementError};
+
+/// A mapping applied to each byte independently.
+///
+/// Returning `None` drops the byte from the output, so one trait covers
+/// maps, filters, and combinations of both.
+pub trait ResourceTransform {
+    /// Maps `byte` to its output, or `None` to drop it.
+    fn transform_byte(&self, byte: u8) -> Option<u8>;
+}
+
+/// XORs every byte with a fixed key.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct XorTransform(pub u8);
+
+impl ResourceTransform for XorTransform {
+    #[inline]
+    fn transform_byte(&self, byte: u8) -> Option<u8> {
+        Some(byte ^ self.0)
+    }
+}
+
+/// Inverts every bit.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct NotTransform;
+
+impl ResourceTransform for NotTransform {
+    #[inline]
+    fn transform_byte(&self, byte: u8) -> Option<u8> {
+        Some(!byte)
+    }
+}
+
+/// Keeps only the bytes for which the predicate returns `true`.
+#[derive(Clone, Copy, Debug)]
+pub struct FilterTransform(pub fn(u8) -> bool);
+
+impl ResourceTransform for FilterTransform {
+    #[inline]
+    fn transform_byte(&self, byte: u8) -> Option<u8> {
+        (self.0)(byte).then_some(byte)
+    }
+}
+
+/// Builds a new resource by passing every byte of `resource` through
+/// `transform`, dropping bytes it maps to `None`.
+///
+/// # Errors
+///
+/// Currently infallible, mirroring [`RawResource::refine`].
+pub fn apply_transform(
+    resource: RawResource,
+    transform: impl ResourceTransform,
+) -> Result<RawResource, RefinementError> {
+    let output = resource
+        .iter()
+        .filter_map(|&byte| transform.transform_byte(byte))
+        .collect();
+    RawResource::refine(output)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_builtin_transforms() {
+        let input = RawResource::from(&[0x00, 0x0F, 0xF0, 0xFF][..]);
+
+        let xored = apply_transform(input.clone(), XorTransform(0xFF)).unwrap();
+        assert_eq!(xored.as_slice(), &[0xFF, 0xF0, 0x0F, 0x00]);
+        let notted = apply_transform(input.clone(), NotTransform).unwrap();
+        assert_eq!(notted, xored);
+
+        let restored = apply_transform(xored, XorTransform(0xFF)).unwrap();
+        assert_eq!(restored, input);
+    }
+
+    #[test]
+    fn test_filter_transform() {
+        let input = RawResource::from("a1b2c3");
+        let digits =
+            apply_transform(input.clone(), FilterTransform(|b| b.is_ascii_digit())).unwrap();
+        assert_eq!(digits.as_slice(), b"123");
+        let none = apply_transform(input, FilterTransform(|_| false)).unwrap();
+        assert!(none.is_empty());
+    }
+
+    #[test]
+    fn test_custom_transform() {
+        struct Uppercase;
+
+        impl ResourceTransform for Uppercase {
+            fn transform_byte(&self, byte: u8) -> Option<u8> {
+                Some(byte.to_ascii_uppercase())
+            }
+        }
+
+        let shouted = apply_transform(RawResource::from("hello"), Uppercase).unwrap();
+        assert_eq!(shouted.as_slice(), b"HELLO");
+    }
+}
@@ -0,0 +1,169 @@
+//! Pluggable invariant checks applied when refining a buffer.
+
+use alloc::vec::Vec;
+
+use crate::{RawResource, RefinementError};
+
+/// An invariant that a buffer must satisfy before it becomes a resource.
+///
+/// Implement this for application-specific checks and pass it to
+/// [`RawResource::refine_validated`].
+pub trait ResourceValidator {
+    /// Checks `data`, returning the reason it is rejected.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`RefinementError`] describing the violated invariant.
+    fn validate(&self, data: &[u8]) -> Result<(), RefinementError>;
+}
+
+/// Rejects empty buffers with [`RefinementError::EmptyBuffer`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct NonEmptyValidator;
+
+impl ResourceValidator for NonEmptyValidator {
+    fn validate(&self, data: &[u8]) -> Result<(), RefinementError> {
+        if data.is_empty() {
+            return Err(RefinementError::EmptyBuffer);
+        }
+        Ok(())
+    }
+}
+
+/// Rejects buffers that are not valid UTF-8 with
+/// [`RefinementError::InvalidUtf8`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Utf8Validator;
+
+impl ResourceValidator for Utf8Validator {
+    fn validate(&self, data: &[u8]) -> Result<(), RefinementError> {
+        core::str::from_utf8(data).map_err(|err| RefinementError::InvalidUtf8 {
+            valid_up_to: err.valid_up_to(),
+        })?;
+        Ok(())
+    }
+}
+
+/// Rejects buffers that do not begin with `magic` with
+/// [`RefinementError::BadMagic`].
+///
+/// ```
+/// use praborrow_logistics::{MagicBytesValidator, RawResource};
+///
+/// const PNG: MagicBytesValidator = MagicBytesValidator { magic: b"\x89PNG" };
+/// assert!(RawResource::refine_validated(b"\x89PNG\r\n".to_vec(), &PNG).is_ok());
+/// assert!(RawResource::refine_validated(b"GIF89a".to_vec(), &PNG).is_err());
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct MagicBytesValidator {
+    /// The required header.
+    pub magic: &'static [u8],
+}
+
+impl ResourceValidator for MagicBytesValidator {
+    fn validate(&self, data: &[u8]) -> Result<(), RefinementError> {
+        if !data.starts_with(self.magic) {
+            return Err(RefinementError::BadMagic);
+        }
+        Ok(())
+    }
+}
+
+impl RawResource {
+    /// Refines `data` after checking it against `validator`.
+    ///
+    /// # Errors
+    ///
+    /// Returns whatever error `validator` reports; `data` is dropped.
+    pub fn refine_validated<V: ResourceValidator + ?Sized>(
+        data: Vec<u8>,
+        validator: &V,
+    ) -> Result<Self, RefinementError> {
+        validator.validate(&data)?;
+        Self::refine(data)
+    }
+
+    /// Refines `data` if it begins with `magic`, in one call.
+    ///
+    /// Equivalent to [`refine_validated`](Self::refine_validated) with a
+    /// [`MagicBytesValidator`], but `magic` need not be `'static`.
+    ///
+    /// ```
+    /// use praborrow_logistics::{RawResource, RefinementError};
+    ///
+    /// let elf = RawResource::refine_with_magic(b"\x7fELF\x02\x01".to_vec(), b"\x7fELF");
+    /// assert!(elf.is_ok());
+    /// let png = RawResource::refine_with_magic(b"\x7fELF".to_vec(), b"\x89PNG");
+    /// assert_eq!(png, Err(RefinementError::BadMagic));
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns [`RefinementError::BadMagic`] if `data` does not start with
+    /// `magic`.
+    pub fn refine_with_magic(data: Vec<u8>, magic: &[u8]) -> Result<Self, RefinementError> {
+        if !data.starts_with(magic) {
+            return Err(RefinementError::BadMagic);
+        }
+        Self::refine(data)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_builtin_validators() {
+        assert_eq!(
+            RawResource::refine_validated(Vec::new(), &NonEmptyValidator),
+            Err(RefinementError::EmptyBuffer)
+        );
+        assert!(RawResource::refine_validated(alloc::vec![0], &NonEmptyValidator).is_ok());
+
+        assert!(RawResource::refine_validated("héllo".into(), &Utf8Validator).is_ok());
+        assert_eq!(
+            RawResource::refine_validated(alloc::vec![b'o', b'k', 0xFF], &Utf8Validator),
+            Err(RefinementError::InvalidUtf8 { valid_up_to: 2 })
+        );
+
+        let elf = MagicBytesValidator { magic: b"\x7fELF" };
+        assert!(RawResource::refine_validated(b"\x7fELF\x02".to_vec(), &elf).is_ok());
+        assert_eq!(
+            RawResource::refine_validated(b"\x7fEL".to_vec(), &elf),
+            Err(RefinementError::BadMagic)
+        );
+    }
+
+    #[test]
+    fn test_refine_with_magic() {
+        let header = alloc::vec![0xCA, 0xFE];
+        let ok = RawResource::refine_with_magic(alloc::vec![0xCA, 0xFE, 0xBA, 0xBE], &header);
+        assert_eq!(ok.unwrap().as_slice(), &[0xCA, 0xFE, 0xBA, 0xBE]);
+        assert_eq!(
+            RawResource::refine_with_magic(alloc::vec![0xCA], &header),
+            Err(RefinementError::BadMagic)
+        );
+        assert!(RawResource::refine_with_magic(Vec::new(), &[]).is_ok());
+    }
+
+    #[test]
+    fn test_custom_validator() {
+        struct EvenLength;
+
+        impl ResourceValidator for EvenLength {
+            fn validate(&self, data: &[u8]) -> Result<(), RefinementError> {
+                if !data.len().is_multiple_of(2) {
+                    return Err(RefinementError::LengthMismatch {
+                        expected: data.len() + 1,
+                        actual: data.len(),
+                    });
+                }
+                Ok(())
+            }
+        }
+
+        assert!(RawResource::refine_validated(alloc::vec![1, 2], &EvenLength).is_ok());
+        assert!(RawResource::refine_validated(alloc::vec![1], &EvenLength).is_err());
+    }
+}
@@ -0,0 +1,181 @@
+//! Borrowed, non-owning views into a resource.
+
+use core::fmt;
+use core::ops::{Deref, Range};
+
+use crate::{RawResource, search};
+
+/// A zero-copy borrow of a byte range within a resource.
+///
+/// Views never own or free memory; the borrow checker ties them to the
+/// resource they were taken from, so they cannot outlive it. Besides being
+/// returned by splitting and searching methods, a view can be made from any
+/// `&[u8]` or `&RawResource`.
+///
+/// Accessors that return bytes borrow from the underlying resource rather
+/// than from the view, so results outlive the (`Copy`) view itself.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct ResourceView<'a> {
+    bytes: &'a [u8],
+}
+
+impl<'a> ResourceView<'a> {
+    pub(crate) fn new(bytes: &'a [u8]) -> Self {
+        Self { bytes }
+    }
+
+    /// Returns the viewed bytes with the lifetime of the underlying resource.
+    #[inline]
+    pub fn as_slice(&self) -> &'a [u8] {
+        self.bytes
+    }
+
+    /// Returns a pointer to the first viewed byte.
+    #[inline]
+    pub fn as_ptr(&self) -> *const u8 {
+        self.bytes.as_ptr()
+    }
+
+    /// Returns the number of viewed bytes.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.bytes.len()
+    }
+
+    /// Returns `true` if the view is empty.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.bytes.is_empty()
+    }
+
+    /// Returns the byte at `index`, or `None` if it is out of bounds.
+    #[inline]
+    pub fn get(&self, index: usize) -> Option<u8> {
+        self.bytes.get(index).copied()
+    }
+
+    /// Returns the bytes in `range`, or `None` if it is out of bounds or
+    /// `range.start > range.end`.
+    #[inline]
+    pub fn get_slice(&self, range: Range<usize>) -> Option<&'a [u8]> {
+        self.bytes.get(range)
+    }
+
+    /// Returns the offset of the first `needle` byte, if any.
+    #[inline]
+    pub fn find_byte(&self, needle: u8) -> Option<usize> {
+        search::find_byte(self.bytes, needle)
+    }
+
+    /// Returns `true` if the view begins with `prefix`.
+    #[inline]
+    pub fn starts_with(&self, prefix: &[u8]) -> bool {
+        self.bytes.starts_with(prefix)
+    }
+
+    /// Returns `true` if the view ends with `suffix`.
+    #[inline]
+    pub fn ends_with(&self, suffix: &[u8]) -> bool {
+        self.bytes.ends_with(suffix)
+    }
+}
+
+impl<'a> From<&'a [u8]> for ResourceView<'a> {
+    #[inline]
+    fn from(bytes: &'a [u8]) -> Self {
+        Self::new(bytes)
+    }
+}
+
+impl<'a> From<&'a RawResource> for ResourceView<'a> {
+    #[inline]
+    fn from(resource: &'a RawResource) -> Self {
+        Self::new(resource.as_slice())
+    }
+}
+
+impl Deref for ResourceView<'_> {
+    type Target = [u8];
+
+    #[inline]
+    fn deref(&self) -> &[u8] {
+        self.bytes
+    }
+}
+
+impl AsRef<[u8]> for ResourceView<'_> {
+    #[inline]
+    fn as_ref(&self) -> &[u8] {
+        self.bytes
+    }
+}
+
+impl fmt::Debug for ResourceView<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        crate::display::debug_resource("ResourceView", self.bytes, f)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{RawResource, ResourceView};
+
+    #[test]
+    fn test_split_at() {
+        let resource = RawResource::refine(alloc::vec![1, 2, 3, 4, 5]).expect("should succeed");
+        let (head, tail) = resource.split_at(2);
+        assert_eq!(&*head, &[1, 2]);
+        assert_eq!(tail.as_slice(), &[3, 4, 5]);
+        assert_eq!(head.as_ptr(), resource.as_ptr());
+    }
+
+    #[test]
+    fn test_split_at_edges() {
+        let resource = RawResource::refine(alloc::vec![1, 2, 3]).expect("should succeed");
+        let (head, tail) = resource.split_at(0);
+        assert!(head.is_empty());
+        assert_eq!(tail.len(), 3);
+        let (head, tail) = resource.split_at(3);
+        assert_eq!(head.len(), 3);
+        assert!(tail.is_empty());
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_split_at_out_of_bounds() {
+        let resource = RawResource::refine(alloc::vec![1, 2, 3]).expect("should succeed");
+        let _ = resource.split_at(4);
+    }
+
+    #[test]
+    fn test_view_comparison_and_debug() {
+        let resource = RawResource::refine(alloc::vec![1, 2, 1, 2]).expect("should succeed");
+        let (a, b) = resource.split_at(2);
+        assert_eq!(a, b);
+        assert!(a <= b);
+        assert_eq!(
+            alloc::format!("{a:?}"),
+            "ResourceView { len: 2, data: \"0102\" }"
+        );
+    }
+
+    #[test]
+    fn test_view_accessors() {
+        let resource = RawResource::from("GET /index.html");
+        let view = ResourceView::from(&resource);
+        assert_eq!(view.len(), 15);
+        assert_eq!(view.as_ptr(), resource.as_ptr());
+        assert!(view.starts_with(b"GET "));
+        assert!(view.ends_with(b".html"));
+        assert_eq!(view.find_byte(b'/'), Some(4));
+        assert_eq!(view.get(0), Some(b'G'));
+        assert_eq!(view.get(15), None);
+        assert_eq!(view.get_slice(4..10), Some(&b"/index"[..]));
+        assert_eq!(view.get_slice(10..16), None);
+
+        let bytes: &[u8] = &[];
+        let empty = ResourceView::from(bytes);
+        assert!(empty.is_empty());
+        assert_eq!(empty.find_byte(0), None);
+    }
+}
@@ -0,0 +1,109 @@
+//! Volatile access to memory-mapped IO regions.
+
+use core::ptr;
+
+/// A non-owning window onto a memory-mapped IO region.
+///
+/// Every access is a volatile read or write, so the compiler never elides,
+/// merges or reorders it relative to other volatile accesses. Unlike
+/// [`RawResource`](crate::RawResource), this type does **not** own its
+/// memory and does **not** free it on drop; the region belongs to the caller
+/// (typically the hardware).
+#[derive(Debug)]
+pub struct VolatileResource {
+    base: *mut u8,
+    len: usize,
+}
+
+impl VolatileResource {
+    /// Wraps the `len`-byte region starting at `base`.
+    ///
+    /// # Safety
+    ///
+    /// For the lifetime of the returned value, `base..base + len` must be
+    /// valid for volatile reads and writes, and must not be accessed through
+    /// references that assume ordinary (non-volatile) memory semantics.
+    pub const unsafe fn new(base: *mut u8, len: usize) -> Self {
+        Self { base, len }
+    }
+
+    /// Returns the base address of the region.
+    #[inline]
+    pub fn as_ptr(&self) -> *mut u8 {
+        self.base
+    }
+
+    /// Returns the length of the region in bytes.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns `true` if the region is empty.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Performs a volatile read of the byte at `offset`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `offset >= len`.
+    #[inline]
+    pub fn read_byte(&self, offset: usize) -> u8 {
+        assert!(
+            offset < self.len,
+            "offset {offset} out of bounds for length {}",
+            self.len
+        );
+        // SAFETY: in bounds, and `new`'s contract makes the region valid for
+        // volatile reads.
+        unsafe { ptr::read_volatile(self.base.add(offset)) }
+    }
+
+    /// Performs a volatile write of `val` to the byte at `offset`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `offset >= len`.
+    #[inline]
+    pub fn write_byte(&mut self, offset: usize, val: u8) {
+        assert!(
+            offset < self.len,
+            "offset {offset} out of bounds for length {}",
+            self.len
+        );
+        // SAFETY: in bounds, and `new`'s contract makes the region valid for
+        // volatile writes.
+        unsafe { ptr::write_volatile(self.base.add(offset), val) }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_volatile_read_write() {
+        let mut registers = [0u8; 4];
+        // SAFETY: `registers` outlives `mmio` and is only accessed through it.
+        let mut mmio = unsafe { VolatileResource::new(registers.as_mut_ptr(), registers.len()) };
+        mmio.write_byte(0, 0xA5);
+        mmio.write_byte(3, 0x5A);
+        assert_eq!(mmio.read_byte(0), 0xA5);
+        assert_eq!(mmio.read_byte(1), 0);
+        assert_eq!(mmio.read_byte(3), 0x5A);
+        assert_eq!(mmio.len(), 4);
+        assert_eq!(registers, [0xA5, 0, 0, 0x5A]);
+    }
+
+    #[test]
+    #[should_panic(expected = "out of bounds")]
+    fn test_volatile_out_of_bounds_panics() {
+        let mut register = 0u8;
+        // SAFETY: `register` outlives `mmio`.
+        let mmio = unsafe { VolatileResource::new(&mut register, 1) };
+        mmio.read_byte(1);
+    }
+}
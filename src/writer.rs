@@ -0,0 +1,202 @@
+//! Positional writes into a mutable resource.
+
+use core::fmt;
+
+use crate::{MutRawResource, RefinementError};
+
+/// A write cursor over a borrowed [`MutRawResource`].
+///
+/// Writes overwrite bytes in place starting at the current position and never
+/// grow the buffer. A write that does not fit fails with
+/// [`RefinementError::Exhausted`] and leaves both the buffer and the position
+/// unchanged.
+///
+/// ```
+/// use praborrow_logistics::{MutRawResource, ResourceWriter};
+///
+/// let mut buf = MutRawResource::refine(vec![0; 5]).unwrap();
+/// let mut writer = ResourceWriter::new(&mut buf);
+/// writer.write_u8(0x7F).unwrap();
+/// writer.write_u32_be(1).unwrap();
+/// assert_eq!(writer.remaining(), 0);
+/// assert_eq!(buf.as_slice(), &[0x7F, 0, 0, 0, 1]);
+/// ```
+#[derive(Debug)]
+pub struct ResourceWriter<'a> {
+    resource: &'a mut MutRawResource,
+    pos: usize,
+}
+
+impl<'a> ResourceWriter<'a> {
+    /// Creates a writer positioned at the start of `resource`.
+    pub fn new(resource: &'a mut MutRawResource) -> Self {
+        Self { resource, pos: 0 }
+    }
+
+    /// Returns the current write position.
+    #[inline]
+    pub fn position(&self) -> usize {
+        self.pos
+    }
+
+    /// Returns the number of bytes that can still be written.
+    #[inline]
+    pub fn remaining(&self) -> usize {
+        self.resource.len() - self.pos
+    }
+
+    /// Writes `s` at the current position.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`RefinementError::Exhausted`] if `s` does not fit.
+    pub fn write_slice(&mut self, s: &[u8]) -> Result<(), RefinementError> {
+        let remaining = self.remaining();
+        if s.len() > remaining {
+            return Err(RefinementError::Exhausted {
+                needed: s.len(),
+                remaining,
+            });
+        }
+        self.resource.as_mut_slice()[self.pos..self.pos + s.len()].copy_from_slice(s);
+        self.pos += s.len();
+        Ok(())
+    }
+
+    /// Writes a single byte.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`RefinementError::Exhausted`] if the buffer is full.
+    pub fn write_u8(&mut self, v: u8) -> Result<(), RefinementError> {
+        self.write_slice(&[v])
+    }
+
+    /// Writes a `u16` in big-endian byte order.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`RefinementError::Exhausted`] if fewer than 2 bytes remain.
+    pub fn write_u16_be(&mut self, v: u16) -> Result<(), RefinementError> {
+        self.write_slice(&v.to_be_bytes())
+    }
+
+    /// Writes a `u32` in big-endian byte order.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`RefinementError::Exhausted`] if fewer than 4 bytes remain.
+    pub fn write_u32_be(&mut self, v: u32) -> Result<(), RefinementError> {
+        self.write_slice(&v.to_be_bytes())
+    }
+
+    /// Writes a `u64` in big-endian byte order.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`RefinementError::Exhausted`] if fewer than 8 bytes remain.
+    pub fn write_u64_be(&mut self, v: u64) -> Result<(), RefinementError> {
+        self.write_slice(&v.to_be_bytes())
+    }
+}
+
+/// Appends UTF-8 text, so `write!` can format directly into the buffer.
+///
+/// A string that does not fit is not written at all and reports `fmt::Error`.
+impl fmt::Write for ResourceWriter<'_> {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        self.write_slice(s.as_bytes()).map_err(|_| fmt::Error)
+    }
+}
+
+/// Writes as many bytes as fit; `Ok(0)` signals that the buffer is full.
+#[cfg(feature = "std")]
+impl std::io::Write for ResourceWriter<'_> {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        let n = buf.len().min(self.remaining());
+        self.resource.as_mut_slice()[self.pos..self.pos + n].copy_from_slice(&buf[..n]);
+        self.pos += n;
+        Ok(n)
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{RawResource, ResourceCursor};
+
+    #[test]
+    fn test_writes_round_trip_through_cursor() {
+        let mut buf = MutRawResource::refine(alloc::vec![0; 15]).expect("should succeed");
+        let mut writer = ResourceWriter::new(&mut buf);
+        writer.write_u8(0xAB).expect("fits");
+        writer.write_u16_be(0x0102).expect("fits");
+        writer.write_u32_be(0x0304_0506).expect("fits");
+        writer.write_u64_be(0x0708_090A_0B0C_0D0E).expect("fits");
+        assert_eq!(writer.remaining(), 0);
+
+        let resource: RawResource = buf.freeze();
+        let mut cursor = ResourceCursor::new(&resource);
+        assert_eq!(cursor.read_u8(), Ok(0xAB));
+        assert_eq!(cursor.read_u16_be(), Ok(0x0102));
+        assert_eq!(cursor.read_u32_be(), Ok(0x0304_0506));
+        assert_eq!(cursor.read_u64_be(), Ok(0x0708_090A_0B0C_0D0E));
+    }
+
+    #[test]
+    fn test_write_macro_formats_header() {
+        use core::fmt::Write;
+
+        let mut buf = MutRawResource::refine(alloc::vec![b'.'; 20]).expect("should succeed");
+        let mut writer = ResourceWriter::new(&mut buf);
+        write!(writer, "PKT {:04x} len={}", 0x2A, 7).expect("fits");
+        let written = writer.position();
+        assert!(writer.write_str("overflowing tail").is_err());
+        assert_eq!(&buf[..written], b"PKT 002a len=7");
+        assert_eq!(&buf[written..], b"......");
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_std_write() {
+        use std::io::Write;
+
+        let mut buf = MutRawResource::refine(alloc::vec![0; 4]).expect("should succeed");
+        let mut writer = ResourceWriter::new(&mut buf);
+        assert_eq!(
+            Write::write(&mut writer, &[1, 2, 3, 4, 5]).expect("infallible"),
+            4
+        );
+        assert_eq!(Write::write(&mut writer, &[6]).expect("infallible"), 0);
+        assert!(writer.write_all(&[7]).is_err());
+        assert_eq!(buf.as_slice(), &[1, 2, 3, 4]);
+    }
+
+    #[test]
+    fn test_write_past_end_is_rejected() {
+        let mut buf = MutRawResource::refine(alloc::vec![0; 3]).expect("should succeed");
+        let mut writer = ResourceWriter::new(&mut buf);
+        writer.write_u8(1).expect("fits");
+        assert_eq!(
+            writer.write_u32_be(u32::MAX),
+            Err(RefinementError::Exhausted {
+                needed: 4,
+                remaining: 2
+            })
+        );
+        assert_eq!(writer.position(), 1);
+        writer.write_slice(&[2, 3]).expect("fits");
+        assert_eq!(
+            writer.write_u8(4),
+            Err(RefinementError::Exhausted {
+                needed: 1,
+                remaining: 0
+            })
+        );
+        assert_eq!(buf.as_slice(), &[1, 2, 3]);
+    }
+}
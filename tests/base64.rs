@@ -0,0 +1,70 @@
+//! RFC 4648 test vectors for base64 encoding and decoding.
+
+#![cfg(feature = "base64")]
+
+use praborrow_logistics::{Base64Error, Base64Variant, RawResource};
+
+const VECTORS: &[(&str, &str)] = &[
+    ("", ""),
+    ("f", "Zg=="),
+    ("fo", "Zm8="),
+    ("foo", "Zm9v"),
+    ("foob", "Zm9vYg=="),
+    ("fooba", "Zm9vYmE="),
+    ("foobar", "Zm9vYmFy"),
+];
+
+#[test]
+fn rfc4648_vectors_round_trip() {
+    for &(plain, encoded) in VECTORS {
+        let resource = RawResource::from(plain);
+        assert_eq!(resource.as_base64(Base64Variant::Standard), encoded);
+        for variant in [Base64Variant::Standard, Base64Variant::UrlSafe] {
+            let decoded = RawResource::refine_from_base64(encoded, variant).unwrap();
+            assert_eq!(decoded.as_slice(), plain.as_bytes());
+        }
+    }
+}
+
+#[test]
+fn unpadded_input_is_accepted() {
+    let decoded = RawResource::refine_from_base64("Zm9vYg", Base64Variant::Standard).unwrap();
+    assert_eq!(decoded.as_slice(), b"foob");
+}
+
+#[test]
+fn alphabets_differ() {
+    let resource = RawResource::from(&[0xfb, 0xff][..]);
+    assert_eq!(resource.as_base64(Base64Variant::Standard), "+/8=");
+    assert_eq!(resource.as_base64(Base64Variant::UrlSafe), "-_8=");
+
+    assert_eq!(
+        RawResource::refine_from_base64("-_8=", Base64Variant::Standard),
+        Err(Base64Error::InvalidByte {
+            index: 0,
+            byte: b'-'
+        })
+    );
+    assert_eq!(
+        RawResource::refine_from_base64("+/8=", Base64Variant::UrlSafe),
+        Err(Base64Error::InvalidByte {
+            index: 0,
+            byte: b'+'
+        })
+    );
+}
+
+#[test]
+fn malformed_input_is_rejected() {
+    assert_eq!(
+        RawResource::refine_from_base64("Zm9vY", Base64Variant::Standard),
+        Err(Base64Error::InvalidLength { len: 5 })
+    );
+    assert_eq!(
+        RawResource::refine_from_base64("Zh==", Base64Variant::Standard),
+        Err(Base64Error::InvalidLastSymbol {
+            index: 1,
+            byte: b'h'
+        })
+    );
+}
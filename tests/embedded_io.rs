@@ -0,0 +1,45 @@
+//! Decoding a simulated UART frame through `embedded_io::Read`.
+
+#![cfg(feature = "embedded-io")]
+
+use embedded_io::Read;
+use praborrow_logistics::{RawResource, ResourceCursor};
+
+/// Frame layout: `0x7E`, length byte, payload, XOR checksum of the payload.
+fn decode_frame<R: Read>(reader: &mut R) -> Option<Vec<u8>> {
+    let mut header = [0u8; 2];
+    reader.read_exact(&mut header).ok()?;
+    if header[0] != 0x7E {
+        return None;
+    }
+    let mut payload = vec![0u8; usize::from(header[1])];
+    reader.read_exact(&mut payload).ok()?;
+    let mut checksum = [0u8; 1];
+    reader.read_exact(&mut checksum).ok()?;
+    let expected = payload.iter().fold(0u8, |acc, b| acc ^ b);
+    (checksum[0] == expected).then_some(payload)
+}
+
+#[test]
+fn decodes_uart_frame_from_resource() {
+    let payload = [0x10, 0x20, 0x30];
+    let checksum = payload.iter().fold(0u8, |acc, b| acc ^ b);
+    let mut wire = vec![0x7E, payload.len() as u8];
+    wire.extend_from_slice(&payload);
+    wire.push(checksum);
+
+    let frame = RawResource::refine(wire).unwrap();
+    let mut cursor = ResourceCursor::new(&frame);
+    assert_eq!(decode_frame(&mut cursor), Some(payload.to_vec()));
+    assert_eq!(cursor.remaining(), 0);
+}
+
+#[test]
+fn truncated_frame_reports_eof() {
+    let frame = RawResource::refine(vec![0x7E, 0x05, 0x01]).unwrap();
+    let mut cursor = ResourceCursor::new(&frame);
+    assert_eq!(decode_frame(&mut cursor), None);
+
+    let mut buf = [0u8; 4];
+    assert_eq!(cursor.read(&mut buf), Ok(0));
+}
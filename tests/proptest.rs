@@ -0,0 +1,52 @@
+//! Property-based tests driven by the crate's own strategies.
+
+#![cfg(feature = "proptest")]
+
+use praborrow_logistics::{
+    RawResource, ResourceDecoder, ResourceEncoder, RleDecoder, RleEncoder, arb_resource,
+    arb_resource_of_len,
+};
+use proptest::prelude::*;
+
+proptest! {
+    #[test]
+    fn generated_lengths_are_in_range(resource in arb_resource()) {
+        prop_assert!((1..=65536).contains(&resource.len()));
+    }
+
+    #[test]
+    fn fixed_length_is_respected(resource in arb_resource_of_len(37)) {
+        prop_assert_eq!(resource.len(), 37);
+    }
+
+    #[test]
+    fn into_vec_round_trips(resource in arb_resource()) {
+        let copy = resource.deep_clone();
+        prop_assert_eq!(RawResource::refine(resource.into_vec()).unwrap(), copy);
+    }
+
+    #[test]
+    fn split_off_preserves_bytes(resource in arb_resource(), mid in any::<prop::sample::Index>()) {
+        let mid = mid.index(resource.len() + 1);
+        let (head, tail) = resource.clone().split_off(mid).unwrap();
+        prop_assert_eq!(head.len() + tail.len(), resource.len());
+        prop_assert_eq!([head.as_slice(), tail.as_slice()].concat(), resource.as_slice());
+    }
+
+    #[test]
+    fn xor_mask_twice_is_identity(
+        resource in arb_resource(),
+        key in prop::collection::vec(any::<u8>(), 1..16),
+    ) {
+        let mut masked = resource.clone();
+        masked.xor_mask_inplace(&key).unwrap();
+        masked.xor_mask_inplace(&key).unwrap();
+        prop_assert_eq!(masked, resource);
+    }
+
+    #[test]
+    fn rle_round_trips(resource in arb_resource(), escape in any::<u8>()) {
+        let encoded = RleEncoder::new(escape).encode(&resource).unwrap();
+        prop_assert_eq!(RleDecoder::new(escape).decode(&encoded).unwrap(), resource);
+    }
+}
@@ -0,0 +1,44 @@
+//! Round trips through a human-readable and a binary serde format.
+
+#![cfg(feature = "serde")]
+
+use praborrow_logistics::RawResource;
+
+#[test]
+fn json_uses_hex_string() {
+    let resource = RawResource::refine(vec![0xDE, 0xAD, 0xBE, 0xEF]).unwrap();
+    let json = serde_json::to_string(&resource).unwrap();
+    assert_eq!(json, r#""deadbeef""#);
+
+    let back: RawResource = serde_json::from_str(&json).unwrap();
+    assert_eq!(back, resource);
+    let upper: RawResource = serde_json::from_str(r#""DEADBEEF""#).unwrap();
+    assert_eq!(upper, resource);
+}
+
+#[test]
+fn json_rejects_invalid_hex() {
+    assert!(serde_json::from_str::<RawResource>(r#""abc""#).is_err());
+    assert!(serde_json::from_str::<RawResource>(r#""zz""#).is_err());
+}
+
+#[test]
+fn bincode_uses_raw_bytes() {
+    let resource = RawResource::from("payload");
+    let encoded = bincode::serialize(&resource).unwrap();
+    // bincode prefixes byte sequences with a u64 length.
+    assert_eq!(encoded.len(), 8 + resource.len());
+    assert_eq!(&encoded[8..], b"payload");
+
+    let back: RawResource = bincode::deserialize(&encoded).unwrap();
+    assert_eq!(back, resource);
+}
+
+#[test]
+fn empty_resource_round_trips() {
+    let empty = RawResource::from("");
+    let back: RawResource = serde_json::from_str(&serde_json::to_string(&empty).unwrap()).unwrap();
+    assert!(back.is_empty());
+    let back: RawResource = bincode::deserialize(&bincode::serialize(&empty).unwrap()).unwrap();
+    assert!(back.is_empty());
+}